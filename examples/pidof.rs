@@ -2,26 +2,11 @@
 
 use std::env;
 use std::error::Error;
-use std::ffi::OsStr;
 use std::io;
 
 use futures::StreamExt as _;
 
-use heim::process::{self, Process, ProcessResult};
-
-async fn compare(process: ProcessResult<Process>, needle: &str) -> Option<process::Pid> {
-    let process = process.ok()?;
-    if needle == process.name().await.ok()? {
-        return Some(process.pid());
-    }
-
-    let command = process.command().await.ok()?;
-    if Some(&OsStr::new(needle)) == command.into_iter().next().as_ref() {
-        return Some(process.pid());
-    }
-
-    None
-}
+use heim::process;
 
 fn main() -> Result<(), Box<dyn Error>> {
     smol::block_on(async {
@@ -36,12 +21,10 @@ fn main() -> Result<(), Box<dyn Error>> {
             }
         };
 
-        let processes = process::processes().await?;
+        let processes = process::find().name(needle).processes().await?;
         futures::pin_mut!(processes);
         while let Some(process) = processes.next().await {
-            if let Some(pid) = compare(process, &needle).await {
-                print!("{} ", pid);
-            }
+            print!("{} ", process?.pid());
         }
 
         println!();
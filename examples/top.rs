@@ -1,20 +1,34 @@
-use std::time::Duration;
-use std::usize;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use futures::prelude::*;
 #[cfg(unix)]
 use heim::cpu::os::unix::loadavg;
 use heim::{
-    process::{self, Process, ProcessResult},
-    units::{ratio, Ratio},
+    process::{self, Attributes, CpuTime, Pid, ProcessResult},
+    units::{ratio, time, Ratio},
 };
 
-async fn usage(process: Process) -> ProcessResult<(process::Process, Ratio)> {
-    let usage_1 = process.cpu_usage().await?;
-    futures_timer::Delay::new(Duration::from_millis(100)).await;
-    let usage_2 = process.cpu_usage().await?;
+/// Loads the accumulated CPU time for all processes.
+async fn cpu_times() -> ProcessResult<HashMap<Pid, CpuTime>> {
+    let mut times = HashMap::new();
+    let processes = process::processes_with(Attributes::CPU_TIME).await?;
+    futures::pin_mut!(processes);
+    while let Some(info) = processes.next().await {
+        if let Ok(info) = info {
+            if let Some(Ok(cpu_time)) = info.cpu_time() {
+                let _ = times.insert(info.pid(), cpu_time.clone());
+            }
+        }
+    }
 
-    Ok((process, usage_2 - usage_1))
+    Ok(times)
+}
+
+fn usage(before: &CpuTime, after: &CpuTime, elapsed: Duration) -> Ratio {
+    let delta = (after.user() - before.user()) + (after.system() - before.system());
+
+    Ratio::new::<ratio::ratio>((delta.get::<time::second>() / elapsed.as_secs_f64()) as f32)
 }
 
 fn main() -> ProcessResult<()> {
@@ -30,25 +44,42 @@ fn main() -> ProcessResult<()> {
             );
         }
 
-        let processes = process::processes()
-            .await?
-            .map_ok(|process| {
-                // Note that there is no `.await` here,
-                // as we want to pass the returned future
-                // into the `.try_buffer_unordered`.
-                usage(process)
-            })
-            .try_buffer_unordered(usize::MAX);
+        let before = cpu_times().await?;
+        let started = Instant::now();
+        futures_timer::Delay::new(Duration::from_millis(100)).await;
+
+        let processes =
+            process::processes_with(Attributes::NAME | Attributes::CPU_TIME | Attributes::USERNAME)
+                .await?;
+        let elapsed = started.elapsed();
         futures::pin_mut!(processes);
 
-        println!("| {:6} | {:40} | {:4} % |", "pid", "name", "CPU");
-        while let Some(res) = processes.next().await {
-            let (process, usage) = res?;
+        println!(
+            "| {:6} | {:12} | {:40} | {:4} % |",
+            "pid", "user", "name", "CPU"
+        );
+        while let Some(info) = processes.next().await {
+            let info = match info {
+                Ok(info) => info,
+                Err(..) => continue,
+            };
+            let name = match info.name() {
+                Some(Ok(name)) => name,
+                _ => continue,
+            };
+            let usage = match (before.get(&info.pid()), info.cpu_time()) {
+                (Some(before), Some(Ok(after))) => usage(before, after, elapsed),
+                _ => Ratio::new::<ratio::ratio>(0.0),
+            };
 
             println!(
-                "| {:6} | {:40} | {:.2} |",
-                process.pid(),
-                process.name().await?,
+                "| {:6} | {:12} | {:40} | {:.2} |",
+                info.pid(),
+                match info.username() {
+                    Some(Ok(username)) => username.as_str(),
+                    _ => "",
+                },
+                name,
                 usage.get::<ratio::percent>()
             );
         }
@@ -19,7 +19,7 @@ pub type Result<T> = result::Result<T, Error>;
 
 /// Error details.
 #[doc(hidden)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum Context {
     /// Invalid data format, unable to parse file.
@@ -113,6 +113,31 @@ impl Error {
         }
     }
 
+    /// Creates a copy of this error, as the same error might be shared by multiple values.
+    ///
+    /// OS errors are copied as is, while the other underlying errors
+    /// are preserved with their kind and message only.
+    ///
+    /// This method is considered to be an internal API
+    /// and should not be used by external parties.
+    #[doc(hidden)]
+    pub fn duplicate(&self) -> Self {
+        let source = match self.source.raw_os_error() {
+            Some(code) => io::Error::from_raw_os_error(code),
+            None => match self.source.get_ref() {
+                Some(inner) => io::Error::new(self.source.kind(), inner.to_string()),
+                None => io::Error::from(self.source.kind()),
+            },
+        };
+
+        Self {
+            source,
+            #[cfg(feature = "backtrace")]
+            backtrace: self.backtrace.clone(),
+            context: self.context.clone(),
+        }
+    }
+
     /// Returns internal OS error kind.
     #[doc(hidden)]
     pub fn kind(&self) -> io::ErrorKind {
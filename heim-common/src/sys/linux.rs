@@ -0,0 +1,43 @@
+//! Linux-specific routines used across `heim` crates.
+
+use std::path::PathBuf;
+
+use crate::utils::iter::{ParseIterator, TryIterator};
+use crate::Result;
+
+/// Parses the `/proc/<pid>/cgroup` file line,
+/// which has the `hierarchy-ID:controller-list:cgroup-path` format.
+pub fn parse_cgroup_line(line: &str) -> Result<(u32, Vec<String>, PathBuf)> {
+    let mut parts = line.splitn(3, ':');
+    let hierarchy_id = parts.try_parse_next()?;
+    let controllers = parts
+        .try_next()?
+        .split(',')
+        .filter(|controller| !controller.is_empty())
+        .map(ToString::to_string)
+        .collect();
+    let path = PathBuf::from(parts.try_next()?);
+
+    Ok((hierarchy_id, controllers, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::parse_cgroup_line;
+
+    #[test]
+    fn test_parse_cgroup_line() {
+        let (id, controllers, path) =
+            parse_cgroup_line("4:cpu,cpuacct:/system.slice/sshd.service").unwrap();
+        assert_eq!(id, 4);
+        assert_eq!(controllers, vec!["cpu", "cpuacct"]);
+        assert_eq!(path, PathBuf::from("/system.slice/sshd.service"));
+
+        let (id, controllers, path) = parse_cgroup_line("0::/user.slice/user-1000.slice").unwrap();
+        assert_eq!(id, 0);
+        assert!(controllers.is_empty());
+        assert_eq!(path, PathBuf::from("/user.slice/user-1000.slice"));
+    }
+}
@@ -13,6 +13,9 @@ pub trait IntoTime {
 #[cfg(unix)]
 pub mod unix;
 
+#[cfg(target_os = "linux")]
+pub mod linux;
+
 cfg_if::cfg_if! {
     if #[cfg(target_os = "windows")] {
         pub mod windows;
@@ -14,7 +14,7 @@ async fn topology() -> Result<u64> {
         let mut acc = HashSet::<u64>::new();
 
         for entry in entries {
-            let entry = entry.map_err(|e| e.into_error())?;
+            let entry = entry.map_err(io::Error::from)?;
             let contents = fs::read_to_string(entry)?;
 
             let core_id = contents.trim().parse()?;
@@ -63,7 +63,7 @@ fn _frequencies() -> impl Iterator<Item = Result<CpuFrequency>> {
     let entries = glob::glob(path.display().to_string().as_str()).expect("Incorrect glob pattern");
 
     entries.map(|try_path| {
-        let path = try_path.map_err(|e| e.into_error())?;
+        let path = try_path.map_err(io::Error::from)?;
 
         let current = current_freq(&path)?;
         let max = max_freq(&path);
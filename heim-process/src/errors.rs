@@ -26,6 +26,20 @@ pub enum ProcessError {
     UnreadablePeb(Pid),
 }
 
+impl ProcessError {
+    /// Creates a copy of this error, as the same error might be shared by multiple values.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn duplicate(&self) -> ProcessError {
+        match self {
+            ProcessError::NoSuchProcess(pid) => ProcessError::NoSuchProcess(*pid),
+            ProcessError::ZombieProcess(pid) => ProcessError::ZombieProcess(*pid),
+            ProcessError::AccessDenied(pid) => ProcessError::AccessDenied(*pid),
+            ProcessError::UnreadablePeb(pid) => ProcessError::UnreadablePeb(*pid),
+            ProcessError::Load(e) => ProcessError::Load(e.duplicate()),
+        }
+    }
+}
+
 impl fmt::Display for ProcessError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
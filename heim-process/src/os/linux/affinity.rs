@@ -0,0 +1,89 @@
+use std::collections::BTreeSet;
+use std::iter::FromIterator;
+
+/// Set of the logical CPUs, identified by their indexes.
+///
+/// See [ProcessExt::cpu_affinity] and [ProcessExt::set_cpu_affinity] methods.
+///
+/// [ProcessExt::cpu_affinity]: ./trait.ProcessExt.html#tymethod.cpu_affinity
+/// [ProcessExt::set_cpu_affinity]: ./trait.ProcessExt.html#tymethod.set_cpu_affinity
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CpuSet(pub(crate) BTreeSet<usize>);
+
+impl CpuSet {
+    /// Creates an empty set.
+    pub fn new() -> CpuSet {
+        CpuSet::default()
+    }
+
+    /// Adds the `cpu` to this set.
+    ///
+    /// Returns `false` if set already contained this CPU.
+    pub fn insert(&mut self, cpu: usize) -> bool {
+        self.0.insert(cpu)
+    }
+
+    /// Removes the `cpu` from this set.
+    ///
+    /// Returns `false` if set did not contain this CPU.
+    pub fn remove(&mut self, cpu: usize) -> bool {
+        self.0.remove(&cpu)
+    }
+
+    /// Checks if the `cpu` is in this set.
+    pub fn contains(&self, cpu: usize) -> bool {
+        self.0.contains(&cpu)
+    }
+
+    /// Returns the number of CPUs in this set.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no CPUs in this set.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the CPU indexes in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Checks if this set is not empty and all CPUs in it are less than `count`,
+    /// which is usually a value returned by the [heim_cpu::logical_count] function.
+    ///
+    /// [heim_cpu::logical_count]: ../../../heim_cpu/fn.logical_count.html
+    pub fn is_valid_for(&self, count: u64) -> bool {
+        match self.0.iter().next_back() {
+            Some(max) => (*max as u64) < count,
+            None => false,
+        }
+    }
+}
+
+impl FromIterator<usize> for CpuSet {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        CpuSet(iter.into_iter().collect())
+    }
+}
+
+impl Extend<usize> for CpuSet {
+    fn extend<T: IntoIterator<Item = usize>>(&mut self, iter: T) {
+        self.0.extend(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CpuSet;
+
+    #[test]
+    fn test_is_valid_for() {
+        let set: CpuSet = vec![0, 2, 3].into_iter().collect();
+
+        assert!(set.is_valid_for(4));
+        assert!(!set.is_valid_for(3));
+        assert!(!CpuSet::new().is_valid_for(4));
+    }
+}
@@ -0,0 +1,108 @@
+use std::path::{Path, PathBuf};
+
+use heim_common::units::{Information, Time};
+
+/// Control group the process belongs to.
+///
+/// See [ProcessExt::cgroups] method.
+///
+/// [ProcessExt::cgroups]: ./trait.ProcessExt.html#tymethod.cgroups
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cgroup {
+    pub(crate) hierarchy_id: u32,
+    pub(crate) controllers: Vec<String>,
+    pub(crate) path: PathBuf,
+    pub(crate) directory: Option<PathBuf>,
+}
+
+impl Cgroup {
+    /// Returns the hierarchy ID.
+    ///
+    /// It is always `0` for the cgroups v2 unified hierarchy.
+    pub fn hierarchy_id(&self) -> u32 {
+        self.hierarchy_id
+    }
+
+    /// Returns the controllers bound to this hierarchy,
+    /// for example `["cpu", "cpuacct"]` or `["name=systemd"]`.
+    ///
+    /// It is always empty for the cgroups v2 unified hierarchy.
+    pub fn controllers(&self) -> &[String] {
+        &self.controllers
+    }
+
+    /// Returns the control group path relative to the hierarchy mount point,
+    /// for example `/system.slice/sshd.service`.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Checks if this control group belongs to the cgroups v2 unified hierarchy.
+    pub fn is_unified(&self) -> bool {
+        self.hierarchy_id == 0 && self.controllers.is_empty()
+    }
+
+    /// Returns the control group directory in the cgroup filesystem,
+    /// for example `/sys/fs/cgroup/system.slice/sshd.service`.
+    ///
+    /// Files like `cpu.stat` or `memory.current` can be read from it.
+    ///
+    /// Hierarchies are expected to be mounted at the usual places
+    /// under the `/sys/fs/cgroup` directory (or the configured sysfs root),
+    /// `None` is returned if directory can't be found.
+    pub fn directory(&self) -> Option<&Path> {
+        self.directory.as_deref()
+    }
+}
+
+/// Resource usage accounted for the cgroups v2 control group.
+///
+/// See [ProcessExt::cgroup_stats] method.
+///
+/// Each value is `None` if the corresponding controller
+/// is not enabled for the control group.
+///
+/// [ProcessExt::cgroup_stats]: ./trait.ProcessExt.html#tymethod.cgroup_stats
+#[derive(Debug, Clone, Default)]
+pub struct CgroupStats {
+    pub(crate) cpu_usage: Option<Time>,
+    pub(crate) cpu_user: Option<Time>,
+    pub(crate) cpu_system: Option<Time>,
+    pub(crate) memory_current: Option<Information>,
+    pub(crate) memory_max: Option<Option<Information>>,
+    pub(crate) pids_current: Option<u64>,
+}
+
+impl CgroupStats {
+    /// Returns total CPU time consumed by the control group (`cpu.stat`, `usage_usec`).
+    pub fn cpu_usage(&self) -> Option<Time> {
+        self.cpu_usage
+    }
+
+    /// Returns CPU time spent in user mode (`cpu.stat`, `user_usec`).
+    pub fn cpu_user(&self) -> Option<Time> {
+        self.cpu_user
+    }
+
+    /// Returns CPU time spent in kernel mode (`cpu.stat`, `system_usec`).
+    pub fn cpu_system(&self) -> Option<Time> {
+        self.cpu_system
+    }
+
+    /// Returns the amount of memory currently used by the control group (`memory.current`).
+    pub fn memory_current(&self) -> Option<Information> {
+        self.memory_current
+    }
+
+    /// Returns the memory usage hard limit (`memory.max`).
+    ///
+    /// `Some(None)` value stands for no limit set (`max`).
+    pub fn memory_max(&self) -> Option<Option<Information>> {
+        self.memory_max
+    }
+
+    /// Returns the number of processes currently in the control group (`pids.current`).
+    pub fn pids_current(&self) -> Option<u64> {
+        self.pids_current
+    }
+}
@@ -0,0 +1,153 @@
+use std::net::SocketAddr;
+use std::os::unix::io::RawFd;
+use std::path::{Path, PathBuf};
+
+/// Kind of the sockets to return by the [ProcessExt::connections] method.
+///
+/// [ProcessExt::connections]: ./trait.ProcessExt.html#tymethod.connections
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionKind {
+    /// All supported sockets.
+    All,
+    /// IPv4 and IPv6 sockets.
+    Inet,
+    /// IPv4 sockets.
+    Inet4,
+    /// IPv6 sockets.
+    Inet6,
+    /// TCP sockets over IPv4 and IPv6.
+    Tcp,
+    /// TCP sockets over IPv4.
+    Tcp4,
+    /// TCP sockets over IPv6.
+    Tcp6,
+    /// UDP sockets over IPv4 and IPv6.
+    Udp,
+    /// UDP sockets over IPv4.
+    Udp4,
+    /// UDP sockets over IPv6.
+    Udp6,
+    /// UNIX domain sockets.
+    Unix,
+}
+
+/// Socket address family.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AddressFamily {
+    /// IPv4 (`AF_INET`).
+    Inet,
+    /// IPv6 (`AF_INET6`).
+    Inet6,
+    /// UNIX domain (`AF_UNIX`).
+    Unix,
+}
+
+/// Socket type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SocketType {
+    /// Connection-based byte stream (`SOCK_STREAM`).
+    Stream,
+    /// Connectionless datagrams (`SOCK_DGRAM`).
+    Datagram,
+    /// Connection-based datagrams (`SOCK_SEQPACKET`).
+    SeqPacket,
+}
+
+/// TCP connection state, see `include/net/tcp_states.h`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TcpState {
+    /// Connection is established (`ESTABLISHED`).
+    Established,
+    /// Connection request was sent (`SYN_SENT`).
+    SynSent,
+    /// Connection request was received and acknowledged (`SYN_RECV`).
+    SynRecv,
+    /// Local side was closed, waiting for the acknowledgement (`FIN_WAIT1`).
+    FinWait1,
+    /// Local side was closed, waiting for the remote side to close (`FIN_WAIT2`).
+    FinWait2,
+    /// Both sides were closed, waiting for the delayed packets (`TIME_WAIT`).
+    TimeWait,
+    /// Socket is not used (`CLOSE`).
+    Close,
+    /// Remote side was closed, waiting for the local side to close (`CLOSE_WAIT`).
+    CloseWait,
+    /// Both sides were closed, waiting for the last acknowledgement (`LAST_ACK`).
+    LastAck,
+    /// Socket is listening for the incoming connections (`LISTEN`).
+    Listen,
+    /// Both sides are closing simultaneously (`CLOSING`).
+    Closing,
+    /// Connection request was received, but not acknowledged yet (`NEW_SYN_RECV`).
+    NewSynRecv,
+}
+
+/// Socket opened by the [Process].
+///
+/// See [ProcessExt::connections] method.
+///
+/// [Process]: ../../struct.Process.html
+/// [ProcessExt::connections]: ./trait.ProcessExt.html#tymethod.connections
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Connection {
+    pub(crate) fd: RawFd,
+    pub(crate) inode: u64,
+    pub(crate) family: AddressFamily,
+    pub(crate) socket_type: SocketType,
+    pub(crate) local_address: Option<SocketAddr>,
+    pub(crate) remote_address: Option<SocketAddr>,
+    pub(crate) path: Option<PathBuf>,
+    pub(crate) state: Option<TcpState>,
+}
+
+impl Connection {
+    /// Returns the file descriptor number.
+    pub fn fd(&self) -> RawFd {
+        self.fd
+    }
+
+    /// Returns the socket inode number.
+    pub fn inode(&self) -> u64 {
+        self.inode
+    }
+
+    /// Returns the socket address family.
+    pub fn family(&self) -> AddressFamily {
+        self.family
+    }
+
+    /// Returns the socket type.
+    pub fn socket_type(&self) -> SocketType {
+        self.socket_type
+    }
+
+    /// Returns the local address of the IPv4 or IPv6 socket.
+    pub fn local_address(&self) -> Option<SocketAddr> {
+        self.local_address
+    }
+
+    /// Returns the remote address of the IPv4 or IPv6 socket.
+    ///
+    /// `None` is returned if socket is not connected, ex. it is a listening TCP socket.
+    pub fn remote_address(&self) -> Option<SocketAddr> {
+        self.remote_address
+    }
+
+    /// Returns the path the UNIX domain socket is bound to.
+    ///
+    /// Names of the sockets in the abstract namespace are starting with the `@` symbol.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns the TCP socket state.
+    ///
+    /// `None` is returned for UDP and UNIX domain sockets.
+    pub fn state(&self) -> Option<TcpState> {
+        self.state
+    }
+}
@@ -0,0 +1,25 @@
+use heim_common::units::Time;
+
+/// Linux-specific extension to process [CpuTime] information.
+///
+/// [CpuTime]: ../../struct.CpuTime.html
+pub trait CpuTimeExt {
+    /// Returns amount of CPU time spent in user mode by the terminated children
+    /// of the process, which were waited for.
+    fn children_user(&self) -> Time;
+
+    /// Returns amount of CPU time spent in kernel by the terminated children
+    /// of the process, which were waited for.
+    fn children_system(&self) -> Time;
+}
+
+#[cfg(target_os = "linux")]
+impl CpuTimeExt for crate::CpuTime {
+    fn children_user(&self) -> Time {
+        self.as_ref().children_user()
+    }
+
+    fn children_system(&self) -> Time {
+        self.as_ref().children_system()
+    }
+}
@@ -0,0 +1,69 @@
+/// Process user IDs.
+///
+/// See [ProcessExt::uids] method.
+///
+/// [ProcessExt::uids]: ./trait.ProcessExt.html#tymethod.uids
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Uids {
+    pub(crate) real: libc::uid_t,
+    pub(crate) effective: libc::uid_t,
+    pub(crate) saved: libc::uid_t,
+    pub(crate) filesystem: libc::uid_t,
+}
+
+impl Uids {
+    /// Returns the real user ID, the user who started the process.
+    pub fn real(&self) -> libc::uid_t {
+        self.real
+    }
+
+    /// Returns the effective user ID, which is used for most of the permission checks.
+    pub fn effective(&self) -> libc::uid_t {
+        self.effective
+    }
+
+    /// Returns the saved set-user-ID.
+    pub fn saved(&self) -> libc::uid_t {
+        self.saved
+    }
+
+    /// Returns the user ID used for the filesystem access checks.
+    pub fn filesystem(&self) -> libc::uid_t {
+        self.filesystem
+    }
+}
+
+/// Process group IDs.
+///
+/// See [ProcessExt::gids] method.
+///
+/// [ProcessExt::gids]: ./trait.ProcessExt.html#tymethod.gids
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Gids {
+    pub(crate) real: libc::gid_t,
+    pub(crate) effective: libc::gid_t,
+    pub(crate) saved: libc::gid_t,
+    pub(crate) filesystem: libc::gid_t,
+}
+
+impl Gids {
+    /// Returns the real group ID.
+    pub fn real(&self) -> libc::gid_t {
+        self.real
+    }
+
+    /// Returns the effective group ID, which is used for most of the permission checks.
+    pub fn effective(&self) -> libc::gid_t {
+        self.effective
+    }
+
+    /// Returns the saved set-group-ID.
+    pub fn saved(&self) -> libc::gid_t {
+        self.saved
+    }
+
+    /// Returns the group ID used for the filesystem access checks.
+    pub fn filesystem(&self) -> libc::gid_t {
+        self.filesystem
+    }
+}
@@ -0,0 +1,24 @@
+/// Number of context switches made by the process.
+///
+/// See [ProcessExt::num_ctx_switches] method.
+///
+/// [ProcessExt::num_ctx_switches]: ./trait.ProcessExt.html#tymethod.num_ctx_switches
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CtxSwitches {
+    pub(crate) voluntary: u64,
+    pub(crate) involuntary: u64,
+}
+
+impl CtxSwitches {
+    /// Returns the number of voluntary context switches,
+    /// for example, when process was waiting for a resource to become available.
+    pub fn voluntary(&self) -> u64 {
+        self.voluntary
+    }
+
+    /// Returns the number of involuntary context switches,
+    /// for example, when process time slice has expired.
+    pub fn involuntary(&self) -> u64 {
+        self.involuntary
+    }
+}
@@ -0,0 +1,168 @@
+use heim_common::units::Time;
+
+/// Per-process statistics from the kernel taskstats interface.
+///
+/// All the values are aggregated over all the process threads,
+/// including the terminated ones.
+///
+/// Delays are accounted only if kernel delay accounting is enabled,
+/// see `Documentation/accounting/delay-accounting.rst` in the kernel sources,
+/// otherwise they are zeroes.
+///
+/// See [DelayAccounting::taskstats] method.
+///
+/// [DelayAccounting::taskstats]: ./struct.DelayAccounting.html#method.taskstats
+#[derive(Debug, Clone, Copy)]
+pub struct Taskstats {
+    pub(crate) version: u16,
+    pub(crate) cpu_count: u64,
+    pub(crate) cpu_delay: Time,
+    pub(crate) blkio_count: u64,
+    pub(crate) blkio_delay: Time,
+    pub(crate) swapin_count: u64,
+    pub(crate) swapin_delay: Time,
+    pub(crate) cpu_run_real: Time,
+    pub(crate) cpu_run_virtual: Time,
+    pub(crate) voluntary_ctx_switches: u64,
+    pub(crate) involuntary_ctx_switches: u64,
+    pub(crate) freepages_count: Option<u64>,
+    pub(crate) freepages_delay: Option<Time>,
+    pub(crate) thrashing_count: Option<u64>,
+    pub(crate) thrashing_delay: Option<Time>,
+}
+
+impl Taskstats {
+    /// Returns the version of the taskstats struct provided by kernel.
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the number of times the process was waiting for the CPU.
+    pub fn cpu_count(&self) -> u64 {
+        self.cpu_count
+    }
+
+    /// Returns the time process spent waiting for the CPU while being runnable.
+    pub fn cpu_delay(&self) -> Time {
+        self.cpu_delay
+    }
+
+    /// Returns the number of times the process was waiting for the block I/O completion.
+    pub fn blkio_count(&self) -> u64 {
+        self.blkio_count
+    }
+
+    /// Returns the time process spent waiting for the block I/O completion.
+    pub fn blkio_delay(&self) -> Time {
+        self.blkio_delay
+    }
+
+    /// Returns the number of times the process was waiting for pages to be swapped in.
+    pub fn swapin_count(&self) -> u64 {
+        self.swapin_count
+    }
+
+    /// Returns the time process spent waiting for pages to be swapped in.
+    pub fn swapin_delay(&self) -> Time {
+        self.swapin_delay
+    }
+
+    /// Returns the wall-clock time process spent running on the CPU.
+    pub fn cpu_run_real(&self) -> Time {
+        self.cpu_run_real
+    }
+
+    /// Returns the time process spent running on the CPU,
+    /// excluding the time hypervisor has given the CPU to the other virtual machines.
+    pub fn cpu_run_virtual(&self) -> Time {
+        self.cpu_run_virtual
+    }
+
+    /// Returns the number of voluntary context switches.
+    pub fn voluntary_ctx_switches(&self) -> u64 {
+        self.voluntary_ctx_switches
+    }
+
+    /// Returns the number of involuntary context switches.
+    pub fn involuntary_ctx_switches(&self) -> u64 {
+        self.involuntary_ctx_switches
+    }
+
+    /// Returns the number of times the process was reclaiming memory.
+    ///
+    /// `None` is returned if kernel does not provide this value (added in taskstats version 7).
+    pub fn freepages_count(&self) -> Option<u64> {
+        self.freepages_count
+    }
+
+    /// Returns the time process spent reclaiming memory.
+    ///
+    /// `None` is returned if kernel does not provide this value (added in taskstats version 7).
+    pub fn freepages_delay(&self) -> Option<Time> {
+        self.freepages_delay
+    }
+
+    /// Returns the number of times the process was waiting for the evicted pages to be read back.
+    ///
+    /// `None` is returned if kernel does not provide this value (added in taskstats version 9).
+    pub fn thrashing_count(&self) -> Option<u64> {
+        self.thrashing_count
+    }
+
+    /// Returns the time process spent waiting for the evicted pages to be read back.
+    ///
+    /// `None` is returned if kernel does not provide this value (added in taskstats version 9).
+    pub fn thrashing_delay(&self) -> Option<Time> {
+        self.thrashing_delay
+    }
+}
+
+/// Process delay accounting information.
+///
+/// Values from the procfs are accounted for the main thread of the process only,
+/// while the [Taskstats] values are aggregated over all the process threads.
+///
+/// See [ProcessExt::delay_accounting] method.
+///
+/// [Taskstats]: ./struct.Taskstats.html
+/// [ProcessExt::delay_accounting]: ./trait.ProcessExt.html#tymethod.delay_accounting
+#[derive(Debug, Clone, Copy)]
+pub struct DelayAccounting {
+    pub(crate) cpu_delay: Option<Time>,
+    pub(crate) blkio_delay: Time,
+    pub(crate) taskstats: Option<Taskstats>,
+}
+
+impl DelayAccounting {
+    /// Returns the time process spent waiting for the CPU while being runnable.
+    ///
+    /// `None` is returned if kernel does not provide the `/proc/<pid>/schedstat` file.
+    pub fn cpu_delay(&self) -> Option<Time> {
+        self.cpu_delay
+    }
+
+    /// Returns the time process spent waiting for the block I/O completion.
+    ///
+    /// It is zero if kernel delay accounting is disabled.
+    pub fn blkio_delay(&self) -> Time {
+        self.blkio_delay
+    }
+
+    /// Returns the time process spent waiting for pages to be swapped in.
+    ///
+    /// `None` is returned if [taskstats] are not available.
+    ///
+    /// [taskstats]: #method.taskstats
+    pub fn swapin_delay(&self) -> Option<Time> {
+        self.taskstats.map(|stats| stats.swapin_delay)
+    }
+
+    /// Returns the full statistics from the kernel taskstats interface.
+    ///
+    /// `None` is returned if kernel does not support taskstats,
+    /// the current process is not in the initial network namespace
+    /// or the taskstats request failed for any other reason.
+    pub fn taskstats(&self) -> Option<Taskstats> {
+        self.taskstats
+    }
+}
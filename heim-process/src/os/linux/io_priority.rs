@@ -0,0 +1,49 @@
+/// I/O scheduling class.
+///
+/// See `man 2 ioprio_set` for details.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IoClass {
+    /// No class was set explicitly, I/O priority is derived from the process niceness.
+    None,
+    /// Process gets the first access to the disk, regardless of what else is going on.
+    RealTime,
+    /// Default scheduling class for processes which have not set a specific I/O priority.
+    BestEffort,
+    /// Process gets disk time only when no other program has asked for it.
+    Idle,
+}
+
+/// Process I/O scheduling class and priority level.
+///
+/// See [ProcessExt::ionice] method.
+///
+/// [ProcessExt::ionice]: ./trait.ProcessExt.html#tymethod.ionice
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IoPriority {
+    pub(crate) class: IoClass,
+    pub(crate) level: u8,
+}
+
+impl IoPriority {
+    /// Creates new I/O priority.
+    ///
+    /// `level` is used by the [RealTime] and [BestEffort] classes only
+    /// and should be in the `0..=7` range, where lower values mean higher priority.
+    ///
+    /// [RealTime]: ./enum.IoClass.html#variant.RealTime
+    /// [BestEffort]: ./enum.IoClass.html#variant.BestEffort
+    pub fn new(class: IoClass, level: u8) -> IoPriority {
+        IoPriority { class, level }
+    }
+
+    /// Returns the I/O scheduling class.
+    pub fn class(&self) -> IoClass {
+        self.class
+    }
+
+    /// Returns the priority level within the scheduling class.
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+}
@@ -0,0 +1,32 @@
+/// Kernel stack frame.
+///
+/// See [ProcessExt::kernel_stack] method.
+///
+/// [ProcessExt::kernel_stack]: ./trait.ProcessExt.html#tymethod.kernel_stack
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StackFrame {
+    pub(crate) function: String,
+    pub(crate) offset: u64,
+    pub(crate) size: u64,
+}
+
+impl StackFrame {
+    /// Returns the kernel function name, ex. `nfs_wait_bit_killable`.
+    pub fn function(&self) -> &str {
+        &self.function
+    }
+
+    /// Returns the instruction offset from the function start.
+    ///
+    /// `0` is returned if kernel has not provided it.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the function size.
+    ///
+    /// `0` is returned if kernel has not provided it.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
@@ -0,0 +1,157 @@
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use heim_common::units::{information, Information};
+
+/// Detailed memory information about the process.
+///
+/// Unlike the [Memory] struct, it takes shared memory pages into account
+/// and allows to calculate the actual memory amount used by the process.
+///
+/// See [ProcessExt::memory_full_info] method.
+///
+/// [Memory]: ../../struct.Memory.html
+/// [ProcessExt::memory_full_info]: ./trait.ProcessExt.html#tymethod.memory_full_info
+#[derive(Debug, Clone)]
+pub struct FullMemory {
+    pub(crate) rss: Information,
+    pub(crate) pss: Information,
+    pub(crate) uss: Information,
+    pub(crate) swap: Information,
+    pub(crate) swap_pss: Information,
+    pub(crate) anonymous: Information,
+}
+
+impl FullMemory {
+    /// Returns resident set size, amount of non-swapped physical memory used by the process.
+    pub fn rss(&self) -> Information {
+        self.rss
+    }
+
+    /// Returns PSS (*proportional set size*), the amount of memory
+    /// shared with other processes, accounted in a way that the amount
+    /// is divided evenly between the processes that share it.
+    pub fn pss(&self) -> Information {
+        self.pss
+    }
+
+    /// Returns USS (*unique set size*), the amount of memory
+    /// which is unique to the process and which would be freed
+    /// if the process was terminated right now.
+    pub fn uss(&self) -> Information {
+        self.uss
+    }
+
+    /// Returns the amount of memory that has been swapped out to disk.
+    pub fn swap(&self) -> Information {
+        self.swap
+    }
+
+    /// Returns the proportional amount of swapped memory,
+    /// divided between the processes that share it.
+    pub fn swap_pss(&self) -> Information {
+        self.swap_pss
+    }
+
+    /// Returns the amount of resident memory that does not belong to any file.
+    pub fn anonymous(&self) -> Information {
+        self.anonymous
+    }
+
+    /// Returns the amount of resident memory backed by files,
+    /// including shared libraries and memory-mapped files.
+    pub fn file_backed(&self) -> Information {
+        if self.rss > self.anonymous {
+            self.rss - self.anonymous
+        } else {
+            Information::new::<information::byte>(0)
+        }
+    }
+}
+
+/// Memory region mapped into the process address space.
+///
+/// See [ProcessExt::memory_maps] method.
+///
+/// [ProcessExt::memory_maps]: ./trait.ProcessExt.html#tymethod.memory_maps
+#[derive(Debug, Clone)]
+pub struct MemoryMap {
+    pub(crate) address: Range<u64>,
+    pub(crate) permissions: String,
+    pub(crate) offset: u64,
+    pub(crate) device: libc::dev_t,
+    pub(crate) inode: u64,
+    pub(crate) path: Option<PathBuf>,
+    pub(crate) size: Information,
+    pub(crate) rss: Information,
+    pub(crate) pss: Information,
+    pub(crate) private_clean: Information,
+    pub(crate) private_dirty: Information,
+    pub(crate) swap: Information,
+}
+
+impl MemoryMap {
+    /// Returns the address range occupied by this mapping.
+    pub fn address(&self) -> Range<u64> {
+        self.address.clone()
+    }
+
+    /// Returns the mapping permissions in the `rwxp` form,
+    /// where `p` stands for private and `s` for shared mapping.
+    pub fn permissions(&self) -> &str {
+        &self.permissions
+    }
+
+    /// Returns the offset into the mapped file.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the device number of the mapped file.
+    pub fn device(&self) -> libc::dev_t {
+        self.device
+    }
+
+    /// Returns the inode of the mapped file, `0` for anonymous mappings.
+    pub fn inode(&self) -> u64 {
+        self.inode
+    }
+
+    /// Returns the mapped file path.
+    ///
+    /// Pseudo-paths like `[heap]`, `[stack]` or `[vdso]` are returned as is,
+    /// `None` is returned for anonymous mappings.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns the mapping size.
+    pub fn size(&self) -> Information {
+        self.size
+    }
+
+    /// Returns the amount of this mapping currently resident in memory.
+    pub fn rss(&self) -> Information {
+        self.rss
+    }
+
+    /// Returns the proportional share of this mapping resident in memory.
+    pub fn pss(&self) -> Information {
+        self.pss
+    }
+
+    /// Returns the amount of clean pages private to this process.
+    pub fn private_clean(&self) -> Information {
+        self.private_clean
+    }
+
+    /// Returns the amount of dirty pages private to this process.
+    pub fn private_dirty(&self) -> Information {
+        self.private_dirty
+    }
+
+    /// Returns the amount of this mapping swapped out to disk.
+    pub fn swap(&self) -> Information {
+        self.swap
+    }
+}
@@ -1,14 +1,50 @@
 //! Linux-specific extensions.
 
+use std::path::{Path, PathBuf};
+
 use heim_common::prelude::BoxStream;
 
-use crate::ProcessResult;
+use crate::{CpuUsage, Pid, Process, ProcessResult};
 
+mod affinity;
+mod cgroup;
+mod connection;
+mod cpu_time;
+mod credentials;
+mod ctx_switches;
+mod delay_accounting;
 mod io_counters;
+mod io_priority;
+mod kernel_stack;
 mod memory;
+mod memory_map;
+mod namespaces;
+mod open_file;
+mod page_faults;
+mod scheduler;
+mod security;
+mod syscall;
+mod thread;
 
+pub use self::affinity::CpuSet;
+pub use self::cgroup::{Cgroup, CgroupStats};
+pub use self::connection::{AddressFamily, Connection, ConnectionKind, SocketType, TcpState};
+pub use self::cpu_time::CpuTimeExt;
+pub use self::credentials::{Gids, Uids};
+pub use self::ctx_switches::CtxSwitches;
+pub use self::delay_accounting::{DelayAccounting, Taskstats};
 pub use self::io_counters::IoCountersExt;
+pub use self::io_priority::{IoClass, IoPriority};
+pub use self::kernel_stack::StackFrame;
 pub use self::memory::MemoryExt;
+pub use self::memory_map::{FullMemory, MemoryMap};
+pub use self::namespaces::{Namespace, NamespaceKind, Namespaces};
+pub use self::open_file::{FileKind, OpenFile};
+pub use self::page_faults::PageFaults;
+pub use self::scheduler::{SchedPolicy, SchedStat, Scheduler};
+pub use self::security::{Capabilities, Capability, CapabilitySet, Seccomp};
+pub use self::syscall::Syscall;
+pub use self::thread::Thread;
 
 /// Linux-specific extension to [Process]
 ///
@@ -25,6 +61,338 @@ pub trait ProcessExt {
     async fn net_io_counters(
         &self,
     ) -> ProcessResult<BoxStream<'_, ProcessResult<heim_net::IoCounters>>>;
+
+    /// Returns stream which yields [threads] of this process.
+    ///
+    /// Threads which are terminated while the stream is consumed are skipped.
+    ///
+    /// [threads]: ./struct.Thread.html
+    async fn threads(&self) -> ProcessResult<BoxStream<'_, ProcessResult<Thread>>>;
+
+    /// Returns stream which yields [files] opened by this process.
+    ///
+    /// Data is fetched from the `/proc/<pid>/fd` and `/proc/<pid>/fdinfo` directories,
+    /// so reading file descriptors of other users processes
+    /// will fail with [`AccessDenied`] error.
+    ///
+    /// [files]: ./struct.OpenFile.html
+    /// [`AccessDenied`]: ../../enum.ProcessError.html#variant.AccessDenied
+    async fn open_files(&self) -> ProcessResult<BoxStream<'_, ProcessResult<OpenFile>>>;
+
+    /// Returns the number of file descriptors opened by this process.
+    async fn num_fds(&self) -> ProcessResult<usize>;
+
+    /// Returns [detailed memory information] about this process,
+    /// including USS, PSS and swapped memory amounts.
+    ///
+    /// Data is fetched from the `/proc/<pid>/smaps_rollup` file,
+    /// or from the `/proc/<pid>/smaps` for Linux versions older than 4.14,
+    /// which is much slower.
+    ///
+    /// [detailed memory information]: ./struct.FullMemory.html
+    async fn memory_full_info(&self) -> ProcessResult<FullMemory>;
+
+    /// Returns stream which yields [memory regions] mapped into this process address space.
+    ///
+    /// [memory regions]: ./struct.MemoryMap.html
+    async fn memory_maps(&self) -> ProcessResult<BoxStream<'_, ProcessResult<MemoryMap>>>;
+
+    /// Returns [user IDs] of this process.
+    ///
+    /// [user IDs]: ./struct.Uids.html
+    async fn uids(&self) -> ProcessResult<Uids>;
+
+    /// Returns [group IDs] of this process.
+    ///
+    /// [group IDs]: ./struct.Gids.html
+    async fn gids(&self) -> ProcessResult<Gids>;
+
+    /// Returns supplementary group IDs of this process.
+    async fn groups(&self) -> ProcessResult<Vec<libc::gid_t>>;
+
+    /// Returns the name of the user owning this process.
+    ///
+    /// User name is resolved for the [real user ID] through the passwd database;
+    /// if there is no matching entry in it, numeric ID is returned as a string.
+    ///
+    /// [real user ID]: ./struct.Uids.html#method.real
+    async fn username(&self) -> ProcessResult<String>;
+
+    /// Returns [capability sets] of this process.
+    ///
+    /// [capability sets]: ./struct.Capabilities.html
+    async fn capabilities(&self) -> ProcessResult<Capabilities>;
+
+    /// Returns [seccomp mode] of this process.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux 3.8+ built with the `CONFIG_SECCOMP` option.
+    ///
+    /// [seccomp mode]: ./enum.Seccomp.html
+    async fn seccomp(&self) -> ProcessResult<Seccomp>;
+
+    /// Checks if the `no_new_privs` bit is set for this process,
+    /// which disallows gaining new privileges via `execve(2)`.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux 4.10+.
+    async fn no_new_privs(&self) -> ProcessResult<bool>;
+
+    /// Returns the speculative store bypass (Spectre v4) mitigation state for this process,
+    /// as reported by kernel, for example `"thread force mitigated"` or `"vulnerable"`.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux 4.17+.
+    async fn speculation_store_bypass(&self) -> ProcessResult<String>;
+
+    /// Returns [namespaces] this process belongs to.
+    ///
+    /// Data is fetched from the `/proc/<pid>/ns` directory,
+    /// which requires the ptrace access to the process.
+    ///
+    /// [namespaces]: ./struct.Namespaces.html
+    async fn namespaces(&self) -> ProcessResult<Namespaces>;
+
+    /// Returns [control groups] this process belongs to.
+    ///
+    /// Both cgroups v1 hierarchies and cgroups v2 unified hierarchy are returned,
+    /// as they are listed in the `/proc/<pid>/cgroup` file.
+    ///
+    /// [control groups]: ./struct.Cgroup.html
+    async fn cgroups(&self) -> ProcessResult<Vec<Cgroup>>;
+
+    /// Returns [resource usage] of the cgroups v2 control group this process belongs to.
+    ///
+    /// `None` is returned if process is not a member of the unified hierarchy
+    /// or its control group directory can't be found.
+    ///
+    /// [resource usage]: ./struct.CgroupStats.html
+    async fn cgroup_stats(&self) -> ProcessResult<Option<CgroupStats>>;
+
+    /// Returns [set of CPUs] this process is allowed to run on.
+    ///
+    /// [set of CPUs]: ./struct.CpuSet.html
+    async fn cpu_affinity(&self) -> ProcessResult<CpuSet>;
+
+    /// Restricts this process to run on the [set of CPUs] given.
+    ///
+    /// Set is validated against the [heim_cpu::logical_count] value first,
+    /// so empty set or set with non-existing CPUs will result in error.
+    ///
+    /// [set of CPUs]: ./struct.CpuSet.html
+    /// [heim_cpu::logical_count]: ../../../heim_cpu/fn.logical_count.html
+    async fn set_cpu_affinity(&self, cpus: &CpuSet) -> ProcessResult<()>;
+
+    /// Returns [set of CPUs] this process may be scheduled on,
+    /// as it is reported by the `Cpus_allowed_list` field of the `/proc/<pid>/status` file.
+    ///
+    /// Unlike the [cpu_affinity](#tymethod.cpu_affinity) method,
+    /// it does not require any permissions to read it for other users processes.
+    ///
+    /// [set of CPUs]: ./struct.CpuSet.html
+    async fn cpus_allowed(&self) -> ProcessResult<CpuSet>;
+
+    /// Returns memory nodes this process is allowed to allocate memory on,
+    /// as it is reported by the `Mems_allowed_list` field of the `/proc/<pid>/status` file.
+    async fn mems_allowed(&self) -> ProcessResult<Vec<usize>>;
+
+    /// Returns process [I/O scheduling class and priority].
+    ///
+    /// [I/O scheduling class and priority]: ./struct.IoPriority.html
+    async fn ionice(&self) -> ProcessResult<IoPriority>;
+
+    /// Set process [I/O scheduling class and priority].
+    ///
+    /// Setting the [RealTime] class or raising priority of other users processes
+    /// requires the `CAP_SYS_ADMIN` or `CAP_SYS_NICE` capabilities,
+    /// see `man 2 ioprio_set` for details.
+    ///
+    /// [I/O scheduling class and priority]: ./struct.IoPriority.html
+    /// [RealTime]: ./enum.IoClass.html#variant.RealTime
+    async fn set_ionice(&self, priority: IoPriority) -> ProcessResult<()>;
+
+    /// Returns the number of [page faults] made by this process and its waited-for children.
+    ///
+    /// [page faults]: ./struct.PageFaults.html
+    async fn page_faults(&self) -> ProcessResult<PageFaults>;
+
+    /// Returns the number of [context switches] made by this process.
+    ///
+    /// [context switches]: ./struct.CtxSwitches.html
+    async fn num_ctx_switches(&self) -> ProcessResult<CtxSwitches>;
+
+    /// Returns the number of threads in this process.
+    async fn num_threads(&self) -> ProcessResult<u64>;
+
+    /// Returns the process group ID of this process.
+    async fn process_group(&self) -> ProcessResult<Pid>;
+
+    /// Returns the session ID of this process.
+    async fn session_id(&self) -> ProcessResult<Pid>;
+
+    /// Returns the controlling terminal device path of this process,
+    /// for example `/dev/pts/3` or `/dev/tty1`.
+    ///
+    /// `None` is returned if process has no controlling terminal
+    /// or if the device can't be found in the `/dev` directory.
+    async fn terminal(&self) -> ProcessResult<Option<PathBuf>>;
+
+    /// Returns the ID of the foreground process group of this process controlling terminal.
+    ///
+    /// `None` is returned if process has no controlling terminal.
+    async fn terminal_process_group(&self) -> ProcessResult<Option<Pid>>;
+
+    /// Returns [scheduling policy], real-time priority and run-queue statistics of this process.
+    ///
+    /// [scheduling policy]: ./struct.Scheduler.html
+    async fn scheduler(&self) -> ProcessResult<Scheduler>;
+
+    /// Returns the name of the kernel function this process is sleeping in.
+    ///
+    /// `None` is returned if process is running or kernel does not expose the wait channel.
+    async fn wchan(&self) -> ProcessResult<Option<String>>;
+
+    /// Returns the kernel stack [frames] of this process, innermost one first.
+    ///
+    /// Reading kernel stack requires `CAP_SYS_ADMIN` capability,
+    /// otherwise [AccessDenied] error is returned.
+    ///
+    /// [frames]: ./struct.StackFrame.html
+    /// [AccessDenied]: ../../enum.ProcessError.html#variant.AccessDenied
+    async fn kernel_stack(&self) -> ProcessResult<Vec<StackFrame>>;
+
+    /// Returns the [system call] this process is currently blocked in.
+    ///
+    /// `None` is returned if process is running or it is blocked not in a system call
+    /// (ex. waiting for a page fault to be resolved).
+    ///
+    /// Same to `ptrace`, it is required to be the process owner
+    /// or to have the `CAP_SYS_PTRACE` capability.
+    ///
+    /// [system call]: ./struct.Syscall.html
+    async fn syscall(&self) -> ProcessResult<Option<Syscall>>;
+
+    /// Returns the current score that the kernel gives to this process
+    /// for the purpose of selecting a process for the OOM killer.
+    ///
+    /// Higher score means that the process is more likely to be selected.
+    async fn oom_score(&self) -> ProcessResult<u32>;
+
+    /// Returns the adjustment value added to the [OOM score] of this process.
+    ///
+    /// [OOM score]: #tymethod.oom_score
+    async fn oom_score_adj(&self) -> ProcessResult<i32>;
+
+    /// Sets the adjustment value added to the [OOM score] of this process.
+    ///
+    /// `value` should be in the `-1000..=1000` range, where `-1000` disables OOM killing
+    /// for this process entirely and `1000` makes it the preferred victim.
+    ///
+    /// Decreasing the value below the lowest one set previously by this process
+    /// requires the `CAP_SYS_RESOURCE` capability.
+    ///
+    /// [OOM score]: #tymethod.oom_score
+    async fn set_oom_score_adj(&self, value: i32) -> ProcessResult<()>;
+
+    /// Returns stream which yields [sockets] of the `kind` opened by this process.
+    ///
+    /// Socket inodes from the `/proc/<pid>/fd` directory are matched
+    /// with the `/proc/<pid>/net/{tcp,tcp6,udp,udp6,unix}` tables,
+    /// so reading sockets of other users processes will fail with [`AccessDenied`] error.
+    ///
+    /// Socket referred by multiple file descriptors is yielded once for each of them.
+    ///
+    /// [sockets]: ./struct.Connection.html
+    /// [`AccessDenied`]: ../../enum.ProcessError.html#variant.AccessDenied
+    async fn connections(
+        &self,
+        kind: ConnectionKind,
+    ) -> ProcessResult<BoxStream<'_, ProcessResult<Connection>>>;
+
+    /// Returns CPU usage measurement, which also accounts the CPU time
+    /// of the terminated children of this process.
+    ///
+    /// Only the children which were waited for by this process are accounted,
+    /// see [CpuTimeExt]. This allows to measure the cost of the short-lived
+    /// processes spawned by this one, ex. compilers spawned by a build system.
+    ///
+    /// Both measurements should be obtained with this method,
+    /// see [Process::cpu_usage] for usage details.
+    /// If only one of them was, children CPU time is not accounted at all.
+    ///
+    /// [CpuTimeExt]: ./trait.CpuTimeExt.html
+    /// [Process::cpu_usage]: ../../struct.Process.html#method.cpu_usage
+    async fn cpu_usage_with_children(&self) -> ProcessResult<CpuUsage>;
+
+    /// Resolves the `path`, as it is seen by this process, into the path accessible
+    /// by the current process.
+    ///
+    /// Processes in the other mount namespaces, such as containers,
+    /// see different filesystem trees, so the paths returned by the [Process::exe]
+    /// and [Process::cwd] methods are meaningless outside of them.
+    /// Absolute `path` is resolved through the `/proc/<pid>/root` directory
+    /// and the relative one through the `/proc/<pid>/cwd` directory.
+    ///
+    /// Accessing the resolved path requires the same permissions as for attaching
+    /// to the process with `ptrace`. Note that absolute symlinks inside the resolved path
+    /// are still followed in the filesystem tree of the current process.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// # use heim_process::{self as process, ProcessResult};
+    /// # use heim_process::os::linux::ProcessExt;
+    /// #
+    /// # #[heim_derive::main]
+    /// # async fn main() -> ProcessResult<()> {
+    /// let process = process::get(1).await?;
+    /// let exe = process.resolve_path(&process.exe().await?);
+    /// println!("{:?}", std::fs::metadata(exe));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [Process::exe]: ../../struct.Process.html#method.exe
+    /// [Process::cwd]: ../../struct.Process.html#method.cwd
+    fn resolve_path(&self, path: &Path) -> PathBuf;
+
+    /// Returns pids of this process in each of the nested PID namespaces it belongs to.
+    ///
+    /// First pid is the one in the PID namespace of the configured procfs root,
+    /// which is the same as [Process::pid], and the last one is the pid
+    /// in the PID namespace of the process itself, ex. in the container.
+    ///
+    /// Requires Linux 4.1+.
+    ///
+    /// [Process::pid]: ../../struct.Process.html#method.pid
+    async fn ns_pids(&self) -> ProcessResult<Vec<Pid>>;
+
+    /// Returns the process, which has the `pid` in the PID namespace of this process.
+    ///
+    /// This is a reverse operation to the [ns_pids] method,
+    /// ex. it allows to find the host process by the pid seen in the container,
+    /// if any process of that container is known.
+    ///
+    /// All processes are scanned in order to find the one matching,
+    /// [`NoSuchProcess`] error is returned if there is none.
+    ///
+    /// [ns_pids]: #tymethod.ns_pids
+    /// [`NoSuchProcess`]: ../../enum.ProcessError.html#variant.NoSuchProcess
+    async fn ns_process(&self, pid: Pid) -> ProcessResult<Process>;
+
+    /// Returns [delay accounting] information for this process,
+    /// which shows how long it was waiting for the CPU, block I/O and swapped out pages.
+    ///
+    /// CPU and block I/O delays are read from the procfs, while the full statistics
+    /// are fetched over the taskstats generic netlink interface if it is available,
+    /// see [DelayAccounting::taskstats].
+    ///
+    /// [delay accounting]: ./struct.DelayAccounting.html
+    /// [DelayAccounting::taskstats]: ./struct.DelayAccounting.html#method.taskstats
+    async fn delay_accounting(&self) -> ProcessResult<DelayAccounting>;
 }
 
 #[cfg(target_os = "linux")]
@@ -37,4 +405,178 @@ impl ProcessExt for crate::Process {
 
         Ok(stream)
     }
+
+    async fn threads(&self) -> ProcessResult<BoxStream<'_, ProcessResult<Thread>>> {
+        self.as_ref().threads().await
+    }
+
+    async fn open_files(&self) -> ProcessResult<BoxStream<'_, ProcessResult<OpenFile>>> {
+        self.as_ref().open_files().await
+    }
+
+    async fn num_fds(&self) -> ProcessResult<usize> {
+        self.as_ref().num_fds().await
+    }
+
+    async fn memory_full_info(&self) -> ProcessResult<FullMemory> {
+        self.as_ref().memory_full_info().await
+    }
+
+    async fn memory_maps(&self) -> ProcessResult<BoxStream<'_, ProcessResult<MemoryMap>>> {
+        self.as_ref().memory_maps().await
+    }
+
+    async fn uids(&self) -> ProcessResult<Uids> {
+        self.as_ref().uids().await
+    }
+
+    async fn gids(&self) -> ProcessResult<Gids> {
+        self.as_ref().gids().await
+    }
+
+    async fn groups(&self) -> ProcessResult<Vec<libc::gid_t>> {
+        self.as_ref().groups().await
+    }
+
+    async fn username(&self) -> ProcessResult<String> {
+        self.as_ref().username().await
+    }
+
+    async fn capabilities(&self) -> ProcessResult<Capabilities> {
+        self.as_ref().capabilities().await
+    }
+
+    async fn seccomp(&self) -> ProcessResult<Seccomp> {
+        self.as_ref().seccomp().await
+    }
+
+    async fn no_new_privs(&self) -> ProcessResult<bool> {
+        self.as_ref().no_new_privs().await
+    }
+
+    async fn speculation_store_bypass(&self) -> ProcessResult<String> {
+        self.as_ref().speculation_store_bypass().await
+    }
+
+    async fn namespaces(&self) -> ProcessResult<Namespaces> {
+        self.as_ref().namespaces().await
+    }
+
+    async fn cgroups(&self) -> ProcessResult<Vec<Cgroup>> {
+        self.as_ref().cgroups().await
+    }
+
+    async fn cgroup_stats(&self) -> ProcessResult<Option<CgroupStats>> {
+        self.as_ref().cgroup_stats().await
+    }
+
+    async fn cpu_affinity(&self) -> ProcessResult<CpuSet> {
+        self.as_ref().cpu_affinity().await
+    }
+
+    async fn set_cpu_affinity(&self, cpus: &CpuSet) -> ProcessResult<()> {
+        self.as_ref().set_cpu_affinity(cpus).await
+    }
+
+    async fn cpus_allowed(&self) -> ProcessResult<CpuSet> {
+        self.as_ref().cpus_allowed().await
+    }
+
+    async fn mems_allowed(&self) -> ProcessResult<Vec<usize>> {
+        self.as_ref().mems_allowed().await
+    }
+
+    async fn ionice(&self) -> ProcessResult<IoPriority> {
+        self.as_ref().ionice().await
+    }
+
+    async fn set_ionice(&self, priority: IoPriority) -> ProcessResult<()> {
+        self.as_ref().set_ionice(priority).await
+    }
+
+    async fn page_faults(&self) -> ProcessResult<PageFaults> {
+        self.as_ref().page_faults().await
+    }
+
+    async fn num_ctx_switches(&self) -> ProcessResult<CtxSwitches> {
+        self.as_ref().num_ctx_switches().await
+    }
+
+    async fn num_threads(&self) -> ProcessResult<u64> {
+        self.as_ref().num_threads().await
+    }
+
+    async fn process_group(&self) -> ProcessResult<Pid> {
+        self.as_ref().process_group().await
+    }
+
+    async fn session_id(&self) -> ProcessResult<Pid> {
+        self.as_ref().session_id().await
+    }
+
+    async fn terminal(&self) -> ProcessResult<Option<PathBuf>> {
+        self.as_ref().terminal().await
+    }
+
+    async fn terminal_process_group(&self) -> ProcessResult<Option<Pid>> {
+        self.as_ref().terminal_process_group().await
+    }
+
+    async fn scheduler(&self) -> ProcessResult<Scheduler> {
+        self.as_ref().scheduler().await
+    }
+
+    async fn wchan(&self) -> ProcessResult<Option<String>> {
+        self.as_ref().wchan().await
+    }
+
+    async fn kernel_stack(&self) -> ProcessResult<Vec<StackFrame>> {
+        self.as_ref().kernel_stack().await
+    }
+
+    async fn syscall(&self) -> ProcessResult<Option<Syscall>> {
+        self.as_ref().syscall().await
+    }
+
+    async fn oom_score(&self) -> ProcessResult<u32> {
+        self.as_ref().oom_score().await
+    }
+
+    async fn oom_score_adj(&self) -> ProcessResult<i32> {
+        self.as_ref().oom_score_adj().await
+    }
+
+    async fn set_oom_score_adj(&self, value: i32) -> ProcessResult<()> {
+        self.as_ref().set_oom_score_adj(value).await
+    }
+
+    async fn connections(
+        &self,
+        kind: ConnectionKind,
+    ) -> ProcessResult<BoxStream<'_, ProcessResult<Connection>>> {
+        self.as_ref().connections(kind).await
+    }
+
+    async fn cpu_usage_with_children(&self) -> ProcessResult<CpuUsage> {
+        let mut usage = self.cpu_usage().await?;
+        usage.with_children = true;
+
+        Ok(usage)
+    }
+
+    fn resolve_path(&self, path: &Path) -> PathBuf {
+        self.as_ref().resolve_path(path)
+    }
+
+    async fn ns_pids(&self) -> ProcessResult<Vec<Pid>> {
+        self.as_ref().ns_pids().await
+    }
+
+    async fn ns_process(&self, pid: Pid) -> ProcessResult<Process> {
+        self.as_ref().ns_process(pid).await.map(Into::into)
+    }
+
+    async fn delay_accounting(&self) -> ProcessResult<DelayAccounting> {
+        self.as_ref().delay_accounting().await
+    }
 }
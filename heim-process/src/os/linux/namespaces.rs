@@ -0,0 +1,121 @@
+/// Linux namespace type.
+///
+/// See `man 7 namespaces` for details.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum NamespaceKind {
+    /// Cgroup root directory.
+    Cgroup,
+    /// System V IPC, POSIX message queues.
+    Ipc,
+    /// Mount points.
+    Mount,
+    /// Network devices, stacks, ports, etc.
+    Net,
+    /// Process IDs.
+    Pid,
+    /// Boot and monotonic clocks.
+    Time,
+    /// User and group IDs.
+    User,
+    /// Hostname and NIS domain name.
+    Uts,
+}
+
+/// Namespace the process belongs to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Namespace {
+    pub(crate) kind: NamespaceKind,
+    pub(crate) inode: u64,
+}
+
+impl Namespace {
+    /// Returns the namespace type.
+    pub fn kind(&self) -> NamespaceKind {
+        self.kind
+    }
+
+    /// Returns the namespace inode number, which identifies the namespace.
+    pub fn inode(&self) -> u64 {
+        self.inode
+    }
+}
+
+/// Namespaces of the process.
+///
+/// See [ProcessExt::namespaces] method.
+///
+/// [ProcessExt::namespaces]: ./trait.ProcessExt.html#tymethod.namespaces
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Namespaces(pub(crate) Vec<Namespace>);
+
+impl Namespaces {
+    /// Returns the process namespace of the `kind` given.
+    ///
+    /// `None` is returned if kernel does not support namespaces of this kind.
+    pub fn get(&self, kind: NamespaceKind) -> Option<Namespace> {
+        self.0.iter().find(|ns| ns.kind == kind).copied()
+    }
+
+    /// Returns an iterator over the process namespaces.
+    pub fn iter(&self) -> impl Iterator<Item = &Namespace> {
+        self.0.iter()
+    }
+
+    /// Checks if both processes are in the same namespace of the `kind` given.
+    pub fn shares(&self, other: &Namespaces, kind: NamespaceKind) -> bool {
+        match (self.get(kind), other.get(kind)) {
+            (Some(left), Some(right)) => left == right,
+            _ => false,
+        }
+    }
+
+    /// Returns kinds of the namespaces which are different for both processes.
+    ///
+    /// Empty iterator means that processes are sharing all the namespaces,
+    /// which usually means they are in the same container (or both are not in any).
+    pub fn differences<'a>(
+        &'a self,
+        other: &'a Namespaces,
+    ) -> impl Iterator<Item = NamespaceKind> + 'a {
+        self.iter()
+            .map(|ns| ns.kind)
+            .filter(move |kind| !self.shares(other, *kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Namespace, NamespaceKind, Namespaces};
+
+    #[test]
+    fn test_differences() {
+        let left = Namespaces(vec![
+            Namespace {
+                kind: NamespaceKind::Net,
+                inode: 4_026_531_833,
+            },
+            Namespace {
+                kind: NamespaceKind::Pid,
+                inode: 4_026_531_836,
+            },
+        ]);
+        let right = Namespaces(vec![
+            Namespace {
+                kind: NamespaceKind::Net,
+                inode: 4_026_531_833,
+            },
+            Namespace {
+                kind: NamespaceKind::Pid,
+                inode: 4_026_532_201,
+            },
+        ]);
+
+        assert!(left.shares(&right, NamespaceKind::Net));
+        assert!(!left.shares(&right, NamespaceKind::Uts));
+        assert_eq!(
+            left.differences(&right).collect::<Vec<_>>(),
+            vec![NamespaceKind::Pid]
+        );
+    }
+}
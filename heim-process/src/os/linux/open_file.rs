@@ -0,0 +1,76 @@
+use std::os::unix::io::RawFd;
+use std::path::{Path, PathBuf};
+
+/// File descriptor opened by the [Process].
+///
+/// See [ProcessExt::open_files] method.
+///
+/// [Process]: ../../struct.Process.html
+/// [ProcessExt::open_files]: ./trait.ProcessExt.html#tymethod.open_files
+#[derive(Debug, Clone)]
+pub struct OpenFile {
+    pub(crate) fd: RawFd,
+    pub(crate) kind: FileKind,
+}
+
+impl OpenFile {
+    /// Returns the file descriptor number.
+    pub fn fd(&self) -> RawFd {
+        self.fd
+    }
+
+    /// Returns what kind of object this file descriptor refers to.
+    pub fn kind(&self) -> &FileKind {
+        &self.kind
+    }
+
+    /// Returns the file path if this descriptor refers to the regular file.
+    pub fn path(&self) -> Option<&Path> {
+        match &self.kind {
+            FileKind::File { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+}
+
+/// Kind of the object referred by the [OpenFile].
+///
+/// [OpenFile]: ./struct.OpenFile.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FileKind {
+    /// Regular file.
+    File {
+        /// Absolute path to the file, as seen by the process.
+        path: PathBuf,
+        /// Current file offset.
+        offset: u64,
+        /// Flags the file was opened with, see `open(2)`.
+        flags: libc::c_int,
+    },
+    /// Socket.
+    Socket {
+        /// Socket inode number.
+        inode: u64,
+    },
+    /// Pipe or FIFO.
+    Pipe {
+        /// Pipe inode number.
+        inode: u64,
+    },
+    /// File descriptor without the backing inode, such as `eventfd`, `epoll` or `timerfd`.
+    AnonInode {
+        /// Anonymous inode type, for example `eventfd` or `eventpoll`.
+        name: String,
+    },
+    /// Character or block device.
+    Device {
+        /// Absolute path to the device file.
+        path: PathBuf,
+    },
+    /// Anything else, for example a directory or a namespace.
+    Other {
+        /// Symlink target of the `/proc/<pid>/fd/<fd>` entry.
+        path: PathBuf,
+    },
+}
@@ -0,0 +1,36 @@
+/// Number of page faults made by the process.
+///
+/// See [ProcessExt::page_faults] method.
+///
+/// [ProcessExt::page_faults]: ./trait.ProcessExt.html#tymethod.page_faults
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PageFaults {
+    pub(crate) minor: u64,
+    pub(crate) major: u64,
+    pub(crate) children_minor: u64,
+    pub(crate) children_major: u64,
+}
+
+impl PageFaults {
+    /// Returns the number of minor faults,
+    /// which have not required loading a memory page from disk.
+    pub fn minor(&self) -> u64 {
+        self.minor
+    }
+
+    /// Returns the number of major faults,
+    /// which have required loading a memory page from disk.
+    pub fn major(&self) -> u64 {
+        self.major
+    }
+
+    /// Returns the number of minor faults made by the waited-for children.
+    pub fn children_minor(&self) -> u64 {
+        self.children_minor
+    }
+
+    /// Returns the number of major faults made by the waited-for children.
+    pub fn children_major(&self) -> u64 {
+        self.children_major
+    }
+}
@@ -0,0 +1,87 @@
+use heim_common::units::Time;
+
+/// Process scheduling policy.
+///
+/// See `man 7 sched` for details.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SchedPolicy {
+    /// Default time-sharing scheduling (`SCHED_OTHER`).
+    Other,
+    /// First-in, first-out real-time scheduling (`SCHED_FIFO`).
+    Fifo,
+    /// Round-robin real-time scheduling (`SCHED_RR`).
+    RoundRobin,
+    /// Scheduling for the CPU-intensive batch processes (`SCHED_BATCH`).
+    Batch,
+    /// Scheduling for the very low priority background jobs (`SCHED_IDLE`).
+    Idle,
+    /// Deadline scheduling (`SCHED_DEADLINE`).
+    Deadline,
+    /// Scheduling implemented by BPF programs (`SCHED_EXT`, Linux 6.12+).
+    Ext,
+    /// Scheduling policy which is not known yet, with its raw value.
+    Unknown(u32),
+}
+
+/// Process run-queue statistics.
+///
+/// Available only if kernel was built with the `CONFIG_SCHED_INFO` option.
+#[derive(Debug, Copy, Clone)]
+pub struct SchedStat {
+    pub(crate) run_time: Time,
+    pub(crate) wait_time: Time,
+    pub(crate) timeslices: u64,
+}
+
+impl SchedStat {
+    /// Returns the time spent on the CPU.
+    pub fn run_time(&self) -> Time {
+        self.run_time
+    }
+
+    /// Returns the time spent waiting on a run-queue.
+    pub fn wait_time(&self) -> Time {
+        self.wait_time
+    }
+
+    /// Returns the number of timeslices run on the CPU.
+    pub fn timeslices(&self) -> u64 {
+        self.timeslices
+    }
+}
+
+/// Process scheduling information.
+///
+/// See [ProcessExt::scheduler] method.
+///
+/// [ProcessExt::scheduler]: ./trait.ProcessExt.html#tymethod.scheduler
+#[derive(Debug, Copy, Clone)]
+pub struct Scheduler {
+    pub(crate) policy: SchedPolicy,
+    pub(crate) rt_priority: u32,
+    pub(crate) stat: Option<SchedStat>,
+}
+
+impl Scheduler {
+    /// Returns the scheduling policy.
+    pub fn policy(&self) -> SchedPolicy {
+        self.policy
+    }
+
+    /// Returns the real-time scheduling priority in the `1..=99` range
+    /// for the [Fifo] and [RoundRobin] policies and `0` for others.
+    ///
+    /// [Fifo]: ./enum.SchedPolicy.html#variant.Fifo
+    /// [RoundRobin]: ./enum.SchedPolicy.html#variant.RoundRobin
+    pub fn rt_priority(&self) -> u32 {
+        self.rt_priority
+    }
+
+    /// Returns the run-queue statistics.
+    ///
+    /// `None` is returned if kernel does not provide the `/proc/<pid>/schedstat` file.
+    pub fn stat(&self) -> Option<SchedStat> {
+        self.stat
+    }
+}
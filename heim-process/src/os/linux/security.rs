@@ -0,0 +1,261 @@
+use std::fmt;
+
+/// Linux capability.
+///
+/// See `man 7 capabilities` for details.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Capability {
+    /// Make arbitrary changes to file UIDs and GIDs.
+    Chown = 0,
+    /// Bypass file read, write, and execute permission checks.
+    DacOverride = 1,
+    /// Bypass file read permission checks and directory read and execute permission checks.
+    DacReadSearch = 2,
+    /// Bypass permission checks on operations that normally require
+    /// the filesystem UID of the process to match the UID of the file.
+    Fowner = 3,
+    /// Don't clear set-user-ID and set-group-ID mode bits when a file is modified.
+    Fsetid = 4,
+    /// Bypass permission checks for sending signals.
+    Kill = 5,
+    /// Make arbitrary manipulations of process GIDs and supplementary GID list.
+    Setgid = 6,
+    /// Make arbitrary manipulations of process UIDs.
+    Setuid = 7,
+    /// Modify capability sets of the process.
+    Setpcap = 8,
+    /// Set the `FS_APPEND_FL` and `FS_IMMUTABLE_FL` inode flags.
+    LinuxImmutable = 9,
+    /// Bind a socket to privileged ports (port numbers less than 1024).
+    NetBindService = 10,
+    /// Unused.
+    NetBroadcast = 11,
+    /// Perform various network-related operations.
+    NetAdmin = 12,
+    /// Use `RAW` and `PACKET` sockets.
+    NetRaw = 13,
+    /// Lock memory.
+    IpcLock = 14,
+    /// Bypass permission checks for operations on System V IPC objects.
+    IpcOwner = 15,
+    /// Load and unload kernel modules.
+    SysModule = 16,
+    /// Perform I/O port operations.
+    SysRawio = 17,
+    /// Use `chroot(2)`.
+    SysChroot = 18,
+    /// Trace arbitrary processes using `ptrace(2)`.
+    SysPtrace = 19,
+    /// Use `acct(2)`.
+    SysPacct = 20,
+    /// Perform a range of system administration operations.
+    SysAdmin = 21,
+    /// Use `reboot(2)` and `kexec_load(2)`.
+    SysBoot = 22,
+    /// Raise process nice value and change scheduling policies.
+    SysNice = 23,
+    /// Override resource limits.
+    SysResource = 24,
+    /// Set system clock.
+    SysTime = 25,
+    /// Use `vhangup(2)` and privileged `ioctl(2)` operations on virtual terminals.
+    SysTtyConfig = 26,
+    /// Create special files using `mknod(2)`.
+    Mknod = 27,
+    /// Establish leases on arbitrary files.
+    Lease = 28,
+    /// Write records to kernel auditing log.
+    AuditWrite = 29,
+    /// Enable and disable kernel auditing.
+    AuditControl = 30,
+    /// Set arbitrary capabilities on a file.
+    Setfcap = 31,
+    /// Override Mandatory Access Control.
+    MacOverride = 32,
+    /// Allow MAC configuration or state changes.
+    MacAdmin = 33,
+    /// Perform privileged `syslog(2)` operations.
+    Syslog = 34,
+    /// Trigger something that will wake up the system.
+    WakeAlarm = 35,
+    /// Employ features that can block system suspend.
+    BlockSuspend = 36,
+    /// Allow reading the audit log via a multicast netlink socket.
+    AuditRead = 37,
+    /// Employ various performance-monitoring mechanisms.
+    Perfmon = 38,
+    /// Employ privileged BPF operations.
+    Bpf = 39,
+    /// Checkpoint and restore operations.
+    CheckpointRestore = 40,
+}
+
+impl Capability {
+    const ALL: [Capability; 41] = [
+        Capability::Chown,
+        Capability::DacOverride,
+        Capability::DacReadSearch,
+        Capability::Fowner,
+        Capability::Fsetid,
+        Capability::Kill,
+        Capability::Setgid,
+        Capability::Setuid,
+        Capability::Setpcap,
+        Capability::LinuxImmutable,
+        Capability::NetBindService,
+        Capability::NetBroadcast,
+        Capability::NetAdmin,
+        Capability::NetRaw,
+        Capability::IpcLock,
+        Capability::IpcOwner,
+        Capability::SysModule,
+        Capability::SysRawio,
+        Capability::SysChroot,
+        Capability::SysPtrace,
+        Capability::SysPacct,
+        Capability::SysAdmin,
+        Capability::SysBoot,
+        Capability::SysNice,
+        Capability::SysResource,
+        Capability::SysTime,
+        Capability::SysTtyConfig,
+        Capability::Mknod,
+        Capability::Lease,
+        Capability::AuditWrite,
+        Capability::AuditControl,
+        Capability::Setfcap,
+        Capability::MacOverride,
+        Capability::MacAdmin,
+        Capability::Syslog,
+        Capability::WakeAlarm,
+        Capability::BlockSuspend,
+        Capability::AuditRead,
+        Capability::Perfmon,
+        Capability::Bpf,
+        Capability::CheckpointRestore,
+    ];
+
+    fn mask(self) -> u64 {
+        1 << (self as u64)
+    }
+}
+
+/// Set of the Linux [capabilities].
+///
+/// [capabilities]: ./enum.Capability.html
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CapabilitySet(pub(crate) u64);
+
+impl CapabilitySet {
+    /// Checks if the `capability` is in this set.
+    pub fn contains(&self, capability: Capability) -> bool {
+        self.0 & capability.mask() != 0
+    }
+
+    /// Returns `true` if there are no capabilities in this set.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns raw bit mask of this set.
+    ///
+    /// It might contain capabilities unknown to this crate,
+    /// which are skipped by the [iter](#method.iter) method.
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns an iterator over the known capabilities in this set.
+    pub fn iter(&self) -> impl Iterator<Item = Capability> + '_ {
+        Capability::ALL
+            .iter()
+            .copied()
+            .filter(move |capability| self.contains(*capability))
+    }
+}
+
+impl fmt::Debug for CapabilitySet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Process capability sets.
+///
+/// See [ProcessExt::capabilities] method.
+///
+/// [ProcessExt::capabilities]: ./trait.ProcessExt.html#tymethod.capabilities
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Capabilities {
+    pub(crate) inheritable: CapabilitySet,
+    pub(crate) permitted: CapabilitySet,
+    pub(crate) effective: CapabilitySet,
+    pub(crate) bounding: CapabilitySet,
+    pub(crate) ambient: Option<CapabilitySet>,
+}
+
+impl Capabilities {
+    /// Returns the capabilities preserved across an `execve(2)`.
+    pub fn inheritable(&self) -> CapabilitySet {
+        self.inheritable
+    }
+
+    /// Returns the limiting superset for the effective capabilities
+    /// that the process may assume.
+    pub fn permitted(&self) -> CapabilitySet {
+        self.permitted
+    }
+
+    /// Returns the capabilities used by the kernel to perform permission checks.
+    pub fn effective(&self) -> CapabilitySet {
+        self.effective
+    }
+
+    /// Returns the capability bounding set.
+    pub fn bounding(&self) -> CapabilitySet {
+        self.bounding
+    }
+
+    /// Returns the ambient capability set.
+    ///
+    /// ## Compatibility
+    ///
+    /// Available for Linux 4.3+, older versions always returns `None`.
+    pub fn ambient(&self) -> Option<CapabilitySet> {
+        self.ambient
+    }
+}
+
+/// Process seccomp mode.
+///
+/// See `man 2 seccomp` for details.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Seccomp {
+    /// Seccomp is not used.
+    Disabled,
+    /// Only `read`, `write`, `_exit` and `sigreturn` system calls are permitted.
+    Strict,
+    /// System calls are filtered with BPF program.
+    Filter,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Capability, CapabilitySet};
+
+    #[test]
+    fn test_capability_set() {
+        let set = CapabilitySet((1 << 21) | (1 << 12) | (1 << 63));
+
+        assert!(set.contains(Capability::SysAdmin));
+        assert!(set.contains(Capability::NetAdmin));
+        assert!(!set.contains(Capability::Chown));
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![Capability::NetAdmin, Capability::SysAdmin]
+        );
+        assert!(CapabilitySet::default().is_empty());
+    }
+}
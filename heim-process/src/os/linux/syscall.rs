@@ -0,0 +1,36 @@
+/// System call the process is currently blocked in.
+///
+/// See [ProcessExt::syscall] method.
+///
+/// [ProcessExt::syscall]: ./trait.ProcessExt.html#tymethod.syscall
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Syscall {
+    pub(crate) number: libc::c_long,
+    pub(crate) args: [u64; 6],
+    pub(crate) stack_pointer: u64,
+    pub(crate) program_counter: u64,
+}
+
+impl Syscall {
+    /// Returns the system call number, which can be compared with the `libc::SYS_*` constants.
+    pub fn number(&self) -> libc::c_long {
+        self.number
+    }
+
+    /// Returns the values of the system call argument registers.
+    ///
+    /// All six registers are returned, even if the system call uses less arguments.
+    pub fn args(&self) -> &[u64; 6] {
+        &self.args
+    }
+
+    /// Returns the user-space stack pointer.
+    pub fn stack_pointer(&self) -> u64 {
+        self.stack_pointer
+    }
+
+    /// Returns the user-space program counter.
+    pub fn program_counter(&self) -> u64 {
+        self.program_counter
+    }
+}
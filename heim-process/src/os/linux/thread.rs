@@ -0,0 +1,62 @@
+use std::time::Instant;
+
+use crate::{CpuTime, CpuUsage, Pid, ProcessResult, Status};
+
+/// Thread of the [Process].
+///
+/// See [ProcessExt::threads] method.
+///
+/// [Process]: ../../struct.Process.html
+/// [ProcessExt::threads]: ./trait.ProcessExt.html#tymethod.threads
+#[derive(Debug, Clone)]
+pub struct Thread {
+    pub(crate) tid: Pid,
+    pub(crate) name: String,
+    pub(crate) status: Status,
+    pub(crate) cpu_time: CpuTime,
+    pub(crate) at: Instant,
+}
+
+impl Thread {
+    /// Returns the thread id.
+    pub fn tid(&self) -> Pid {
+        self.tid
+    }
+
+    /// Returns the thread name.
+    ///
+    /// Same to the process name, it is truncated by kernel to the 15 characters.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns current thread status.
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Returns accumulated thread time.
+    pub fn cpu_time(&self) -> &CpuTime {
+        &self.cpu_time
+    }
+
+    /// Returns thread CPU usage measurement, taken at the moment this thread was loaded.
+    ///
+    /// Same to [Process::cpu_usage], measurement of the same thread (matched by [tid])
+    /// from the next [ProcessExt::threads] call should be subtracted by this one.
+    ///
+    /// [Process::cpu_usage]: ../../struct.Process.html#method.cpu_usage
+    /// [tid]: #method.tid
+    /// [ProcessExt::threads]: ./trait.ProcessExt.html#tymethod.threads
+    pub async fn cpu_usage(&self) -> ProcessResult<CpuUsage> {
+        let cpu_count = heim_cpu::logical_count().await?;
+
+        Ok(CpuUsage {
+            cpu_count,
+            cpu_time: self.cpu_time.clone(),
+            at: self.at,
+            #[cfg(target_os = "linux")]
+            with_children: false,
+        })
+    }
+}
@@ -2,8 +2,12 @@
 
 use crate::ProcessResult;
 
+mod resource_usage;
+mod rlimit;
 mod signal;
 
+pub use self::resource_usage::ResourceUsage;
+pub use self::rlimit::{Limit, Limits, Resource};
 pub use self::signal::Signal;
 
 /// Unix-specific extension to [Process].
@@ -12,6 +16,16 @@ pub use self::signal::Signal;
 #[async_trait::async_trait]
 pub trait ProcessExt {
     /// Send the signal to process.
+    ///
+    /// Before the signal send, it checks whether process PID has been reused,
+    /// and if it is a case, [`NoSuchProcess`] error will be returned.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Linux 5.3+ signal is sent via the process file descriptor (`pidfd`),
+    /// so it can't be delivered to another process which reused the same PID.
+    ///
+    /// [`NoSuchProcess`]: ../../enum.ProcessError.html#variant.NoSuchProcess
     async fn signal(&self, signal: Signal) -> ProcessResult<()>;
 
     /// Get process niceness.
@@ -19,6 +33,23 @@ pub trait ProcessExt {
 
     /// Set process niceness.
     async fn set_niceness(&self, value: libc::c_int) -> ProcessResult<()>;
+
+    /// Returns process [resource limits].
+    ///
+    /// Data is fetched from the `/proc/<pid>/limits` file.
+    ///
+    /// [resource limits]: ./struct.Limits.html
+    #[cfg(target_os = "linux")] // TODO: will be undocumented for other platforms
+    async fn rlimits(&self) -> ProcessResult<Limits>;
+
+    /// Set process [resource limit].
+    ///
+    /// Changing limits of other processes requires the `CAP_SYS_RESOURCE` capability
+    /// or the same real, effective and saved user IDs, see `man 2 prlimit`.
+    ///
+    /// [resource limit]: ./struct.Limit.html
+    #[cfg(target_os = "linux")] // TODO: will be undocumented for other platforms
+    async fn set_rlimit(&self, resource: Resource, limit: Limit) -> ProcessResult<()>;
 }
 
 #[cfg(unix)]
@@ -35,4 +66,33 @@ impl ProcessExt for crate::Process {
     async fn set_niceness(&self, value: libc::c_int) -> ProcessResult<()> {
         self.as_ref().set_niceness(value).await
     }
+
+    #[cfg(target_os = "linux")]
+    async fn rlimits(&self) -> ProcessResult<Limits> {
+        self.as_ref().rlimits().await
+    }
+
+    #[cfg(target_os = "linux")]
+    async fn set_rlimit(&self, resource: Resource, limit: Limit) -> ProcessResult<()> {
+        self.as_ref().set_rlimit(resource, limit).await
+    }
+}
+
+/// Unix-specific extension to [Child].
+///
+/// [Child]: ../../struct.Child.html
+pub trait ChildExt {
+    /// Returns the resource usage of the terminated child process.
+    ///
+    /// `None` is returned if the child was not reaped yet by the [Child::wait] method.
+    ///
+    /// [Child::wait]: ../../struct.Child.html#method.wait
+    fn resource_usage(&self) -> Option<ResourceUsage>;
+}
+
+#[cfg(unix)]
+impl ChildExt for crate::Child {
+    fn resource_usage(&self) -> Option<ResourceUsage> {
+        self.usage
+    }
 }
@@ -0,0 +1,61 @@
+use heim_common::units::{Information, Time};
+
+/// Resource usage of the terminated child process.
+///
+/// Values are collected by the `wait4` system call and include
+/// the resources used by the child descendants it had waited for,
+/// see `man 2 getrusage`.
+///
+/// See [ChildExt::resource_usage] method.
+///
+/// [ChildExt::resource_usage]: ./trait.ChildExt.html#tymethod.resource_usage
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceUsage {
+    pub(crate) max_rss: Information,
+    pub(crate) user_time: Time,
+    pub(crate) system_time: Time,
+    pub(crate) minor_faults: u64,
+    pub(crate) major_faults: u64,
+    pub(crate) voluntary_ctx_switches: u64,
+    pub(crate) involuntary_ctx_switches: u64,
+}
+
+impl ResourceUsage {
+    /// Returns the peak resident set size of the process.
+    ///
+    /// If the child had waited for its own descendants,
+    /// the largest value among them is returned.
+    pub fn max_rss(&self) -> Information {
+        self.max_rss
+    }
+
+    /// Returns the time process spent in the user mode.
+    pub fn user_time(&self) -> Time {
+        self.user_time
+    }
+
+    /// Returns the time process spent in the kernel mode.
+    pub fn system_time(&self) -> Time {
+        self.system_time
+    }
+
+    /// Returns the number of page faults serviced without any I/O activity.
+    pub fn minor_faults(&self) -> u64 {
+        self.minor_faults
+    }
+
+    /// Returns the number of page faults which required I/O activity.
+    pub fn major_faults(&self) -> u64 {
+        self.major_faults
+    }
+
+    /// Returns the number of voluntary context switches.
+    pub fn voluntary_ctx_switches(&self) -> u64 {
+        self.voluntary_ctx_switches
+    }
+
+    /// Returns the number of involuntary context switches.
+    pub fn involuntary_ctx_switches(&self) -> u64 {
+        self.involuntary_ctx_switches
+    }
+}
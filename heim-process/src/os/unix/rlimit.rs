@@ -0,0 +1,97 @@
+/// Resource which usage can be limited.
+///
+/// See `man 2 getrlimit` for details.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Resource {
+    /// CPU time in seconds (`RLIMIT_CPU`).
+    Cpu,
+    /// Maximum size of files the process may create, in bytes (`RLIMIT_FSIZE`).
+    FileSize,
+    /// Maximum size of the process data segment, in bytes (`RLIMIT_DATA`).
+    Data,
+    /// Maximum size of the process stack, in bytes (`RLIMIT_STACK`).
+    Stack,
+    /// Maximum size of a core file, in bytes (`RLIMIT_CORE`).
+    Core,
+    /// Maximum resident set size, in bytes (`RLIMIT_RSS`).
+    Rss,
+    /// Maximum number of processes for the process real user ID (`RLIMIT_NPROC`).
+    NumProcesses,
+    /// Maximum file descriptor number plus one (`RLIMIT_NOFILE`).
+    NumFiles,
+    /// Maximum number of bytes of memory that may be locked into RAM (`RLIMIT_MEMLOCK`).
+    MemoryLock,
+    /// Maximum size of the process virtual memory, in bytes (`RLIMIT_AS`).
+    AddressSpace,
+    /// Maximum number of file locks (`RLIMIT_LOCKS`).
+    Locks,
+    /// Maximum number of queued signals (`RLIMIT_SIGPENDING`).
+    SignalsPending,
+    /// Maximum number of bytes allocated for POSIX message queues (`RLIMIT_MSGQUEUE`).
+    MessageQueue,
+    /// Ceiling of the process nice value (`RLIMIT_NICE`).
+    Nice,
+    /// Ceiling of the real-time priority (`RLIMIT_RTPRIO`).
+    RealtimePriority,
+    /// CPU time in microseconds for real-time process without a blocking system call (`RLIMIT_RTTIME`).
+    RealtimeTimeout,
+}
+
+/// Soft and hard limits for the [resource] usage.
+///
+/// `None` value stands for the unlimited resource usage (`RLIM_INFINITY`).
+///
+/// [resource]: ./enum.Resource.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Limit {
+    pub(crate) soft: Option<u64>,
+    pub(crate) hard: Option<u64>,
+}
+
+impl Limit {
+    /// Creates new limit with the `soft` and `hard` values given.
+    pub fn new(soft: Option<u64>, hard: Option<u64>) -> Limit {
+        Limit { soft, hard }
+    }
+
+    /// Creates new limit without any restrictions.
+    pub fn unlimited() -> Limit {
+        Limit::new(None, None)
+    }
+
+    /// Returns the soft limit, which is enforced by the kernel.
+    pub fn soft(&self) -> Option<u64> {
+        self.soft
+    }
+
+    /// Returns the hard limit, which acts as a ceiling for the soft limit.
+    pub fn hard(&self) -> Option<u64> {
+        self.hard
+    }
+}
+
+/// Resource limits of the process.
+///
+/// See [ProcessExt::rlimits] method.
+///
+/// [ProcessExt::rlimits]: ./trait.ProcessExt.html#tymethod.rlimits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Limits(pub(crate) Vec<(Resource, Limit)>);
+
+impl Limits {
+    /// Returns limit for the `resource` given.
+    ///
+    /// `None` is returned if the resource is not supported by the kernel.
+    pub fn get(&self, resource: Resource) -> Option<Limit> {
+        self.0
+            .iter()
+            .find(|(known, _)| *known == resource)
+            .map(|(_, limit)| *limit)
+    }
+
+    /// Returns an iterator over the process resource limits.
+    pub fn iter(&self) -> impl Iterator<Item = (Resource, Limit)> + '_ {
+        self.0.iter().copied()
+    }
+}
@@ -1,7 +1,7 @@
 use std::ops;
 use std::time::Instant;
 
-use heim_common::units::{ratio, time, Ratio};
+use heim_common::units::{ratio, time, Ratio, Time};
 
 use super::CpuTime;
 
@@ -13,6 +13,30 @@ pub struct CpuUsage {
     pub(crate) cpu_count: u64,
     pub(crate) cpu_time: CpuTime,
     pub(crate) at: Instant,
+    #[cfg(target_os = "linux")]
+    pub(crate) with_children: bool,
+}
+
+impl CpuUsage {
+    /// Returns CPU time spent by the reaped children between two measurements.
+    ///
+    /// Children are accounted only if both measurements were requested to include them.
+    #[cfg(target_os = "linux")]
+    fn children_delta(&self, rhs: &CpuUsage) -> Time {
+        use crate::os::linux::CpuTimeExt;
+
+        if self.with_children && rhs.with_children {
+            (self.cpu_time.children_user() - rhs.cpu_time.children_user())
+                + (self.cpu_time.children_system() - rhs.cpu_time.children_system())
+        } else {
+            Time::new::<time::second>(0.0)
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn children_delta(&self, _rhs: &CpuUsage) -> Time {
+        Time::new::<time::second>(0.0)
+    }
 }
 
 impl ops::Sub<CpuUsage> for CpuUsage {
@@ -21,7 +45,8 @@ impl ops::Sub<CpuUsage> for CpuUsage {
     #[allow(clippy::suspicious_arithmetic_impl, clippy::cast_lossless)]
     fn sub(self, rhs: CpuUsage) -> Self::Output {
         let delta_proc = (self.cpu_time.user() - rhs.cpu_time.user())
-            + (self.cpu_time.system() - rhs.cpu_time.system());
+            + (self.cpu_time.system() - rhs.cpu_time.system())
+            + self.children_delta(&rhs);
         let delta_time = self.at - rhs.at;
 
         let mut delta_time_secs = delta_time.as_secs_f64();
@@ -0,0 +1,189 @@
+use std::cmp;
+use std::collections::{HashMap, VecDeque};
+use std::process::ExitStatus;
+use std::time::Duration;
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use super::{processes, Process};
+#[cfg(target_os = "linux")]
+use crate::sys;
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// How often processes list is re-scanned if there is no better events source.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Process lifecycle event.
+///
+/// See [events] function.
+///
+/// [events]: ./fn.events.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Event {
+    /// New process was created.
+    Fork {
+        /// Parent process pid.
+        parent: Pid,
+        /// Created process pid.
+        child: Pid,
+    },
+    /// Process replaced its executable image with the new one.
+    Exec {
+        /// Process pid.
+        pid: Pid,
+    },
+    /// Process was terminated.
+    Exit {
+        /// Terminated process pid.
+        pid: Pid,
+        /// Process exit status, if it is known.
+        status: Option<ExitStatus>,
+    },
+}
+
+impl Event {
+    /// Returns pid of the process this event is about.
+    ///
+    /// For [Fork] event it is the pid of the created process.
+    ///
+    /// [Fork]: ./enum.Event.html#variant.Fork
+    pub fn pid(&self) -> Pid {
+        match self {
+            Event::Fork { child, .. } => *child,
+            Event::Exec { pid } => *pid,
+            Event::Exit { pid, .. } => *pid,
+        }
+    }
+}
+
+/// Returns an infinite stream of the processes creation, execution and termination events.
+///
+/// Only the events happened after this function call are yielded.
+///
+/// ## Compatibility
+///
+/// For Linux the process events connector is used, which requires
+/// `CAP_NET_ADMIN` capability and should be called from the initial PID and user namespaces.
+///
+/// If it is not available, processes list is re-scanned every second
+/// and compared with the previous one in order to find out created and terminated processes.
+/// In that case:
+///
+///  * [Exec] events are not reported
+///  * [Exit] events do not contain exit status
+///  * Processes which were started and terminated between the scans are not noticed
+///
+/// [Exec]: ./enum.Event.html#variant.Exec
+/// [Exit]: ./enum.Event.html#variant.Exit
+pub async fn events() -> ProcessResult<impl Stream<Item = ProcessResult<Event>>> {
+    #[cfg(target_os = "linux")]
+    {
+        if let Some(stream) = sys::events().await? {
+            return Ok(stream.boxed());
+        }
+    }
+
+    let poller = Poller::new().await?;
+    let stream = stream::unfold(poller, |mut poller| async move {
+        let event = poller.next().await;
+        Some((event, poller))
+    });
+
+    Ok(stream.boxed())
+}
+
+/// Fallback events source, which diffs the processes lists.
+///
+/// Processes are compared by their pids and creation time,
+/// so the re-used pid is reported as a termination of the old process and a creation of the new one.
+struct Poller {
+    interval: rt::time::Interval,
+    known: HashMap<Pid, Process>,
+    pending: VecDeque<Event>,
+}
+
+impl Poller {
+    async fn new() -> ProcessResult<Poller> {
+        let mut poller = Poller {
+            interval: rt::time::interval(POLL_INTERVAL),
+            known: HashMap::new(),
+            pending: VecDeque::new(),
+        };
+        poller.scan().await?;
+        // Initial scan is a baseline, there is nothing to report yet
+        poller.pending.clear();
+
+        Ok(poller)
+    }
+
+    async fn next(&mut self) -> ProcessResult<Event> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(event);
+            }
+
+            let _ = self.interval.next().await;
+            self.scan().await?;
+        }
+    }
+
+    async fn scan(&mut self) -> ProcessResult<()> {
+        let mut current = HashMap::with_capacity(self.known.len());
+        let mut created = Vec::new();
+
+        let mut stream = Box::pin(processes().await?);
+        while let Some(process) = stream.next().await {
+            let process = match process {
+                Ok(process) => process,
+                Err(ProcessError::NoSuchProcess(..)) | Err(ProcessError::AccessDenied(..)) => {
+                    continue
+                }
+                Err(e) => return Err(e),
+            };
+            let pid = process.pid();
+            if self.known.get(&pid) != Some(&process) {
+                let parent = match process.parent_pid().await {
+                    Ok(ppid) => ppid,
+                    // Process will be checked again during the next scan
+                    Err(ProcessError::NoSuchProcess(..))
+                    | Err(ProcessError::AccessDenied(..))
+                    | Err(ProcessError::ZombieProcess(..)) => continue,
+                    Err(e) => return Err(e),
+                };
+                created.push((process.create_time().await?, parent, pid));
+            }
+            let _ = current.insert(pid, process);
+        }
+
+        let mut terminated = self
+            .known
+            .iter()
+            .filter(|(pid, process)| current.get(pid) != Some(process))
+            .map(|(pid, _)| *pid)
+            .collect::<Vec<_>>();
+        terminated.sort_unstable();
+        self.pending.extend(
+            terminated
+                .into_iter()
+                .map(|pid| Event::Exit { pid, status: None }),
+        );
+
+        // Parents are reported before their children
+        created.sort_unstable_by(|left, right| {
+            (left.0, left.2)
+                .partial_cmp(&(right.0, right.2))
+                .unwrap_or(cmp::Ordering::Equal)
+        });
+        self.pending.extend(
+            created
+                .into_iter()
+                .map(|(_, parent, child)| Event::Fork { parent, child }),
+        );
+
+        self.known = current;
+
+        Ok(())
+    }
+}
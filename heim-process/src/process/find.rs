@@ -0,0 +1,316 @@
+use std::ffi::OsStr;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use heim_common::prelude::*;
+use heim_common::units::time;
+
+use super::info::snapshots;
+use super::{Attributes, Process, ProcessInfo};
+use crate::sys::MAX_NAME_LEN;
+use crate::{Pid, ProcessError, ProcessResult};
+
+#[derive(Debug, Clone)]
+enum Pattern {
+    Glob(String),
+    Regex(String),
+}
+
+#[derive(Debug)]
+enum Matcher {
+    Glob(glob::Pattern),
+    Regex(regex::Regex),
+}
+
+impl Matcher {
+    fn compile(pattern: &Pattern) -> Result<Matcher> {
+        let matcher = match pattern {
+            Pattern::Glob(pattern) => glob::Pattern::new(pattern)
+                .map(Matcher::Glob)
+                .map_err(|e| e.to_string()),
+            Pattern::Regex(pattern) => regex::Regex::new(pattern)
+                .map(Matcher::Regex)
+                .map_err(|e| e.to_string()),
+        };
+
+        matcher
+            .map_err(|e| Error::from(io::Error::from(io::ErrorKind::InvalidInput)).with_message(e))
+    }
+
+    fn is_match(&self, value: &str) -> bool {
+        match self {
+            Matcher::Glob(pattern) => pattern.matches(value),
+            Matcher::Regex(regex) => regex.is_match(value),
+        }
+    }
+}
+
+/// Processes search query.
+///
+/// See [find] function.
+///
+/// [find]: ./fn.find.html
+#[derive(Debug, Clone, Default)]
+pub struct Finder {
+    name: Option<String>,
+    exe: Option<PathBuf>,
+    command: Option<Pattern>,
+    #[cfg(target_os = "linux")]
+    user: Option<String>,
+    parent_pid: Option<Pid>,
+    older_than: Option<Duration>,
+    newer_than: Option<Duration>,
+}
+
+impl Finder {
+    /// Matches processes with the exact `name`, see [Process::name].
+    ///
+    /// Even if the process name is truncated by the operating system
+    /// (ex. Linux keeps only first 15 bytes of it), full name is restored
+    /// from the process command line or executable path.
+    ///
+    /// Same to the `pidof` utility, processes with the first command line argument
+    /// equal to the `name` are matched too.
+    ///
+    /// [Process::name]: ./struct.Process.html#method.name
+    pub fn name<T: Into<String>>(mut self, name: T) -> Finder {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Matches processes with the `exe` executable path, see [Process::exe].
+    ///
+    /// [Process::exe]: ./struct.Process.html#method.exe
+    pub fn exe<T: Into<PathBuf>>(mut self, exe: T) -> Finder {
+        self.exe = Some(exe.into());
+        self
+    }
+
+    /// Matches processes which whole command line matches the glob `pattern`,
+    /// ex. `*nginx*-c /etc/nginx/*`.
+    ///
+    /// Command line arguments are joined with spaces, see [Command::to_os_string].
+    ///
+    /// [Command::to_os_string]: ./struct.Command.html#method.to_os_string
+    pub fn command_glob<T: Into<String>>(mut self, pattern: T) -> Finder {
+        self.command = Some(Pattern::Glob(pattern.into()));
+        self
+    }
+
+    /// Matches processes which command line contains a match for the regular expression `pattern`.
+    ///
+    /// Command line arguments are joined with spaces, see [Command::to_os_string].
+    ///
+    /// [Command::to_os_string]: ./struct.Command.html#method.to_os_string
+    pub fn command_regex<T: Into<String>>(mut self, pattern: T) -> Finder {
+        self.command = Some(Pattern::Regex(pattern.into()));
+        self
+    }
+
+    /// Matches processes owned by the user with the `name`,
+    /// see [ProcessExt::username].
+    ///
+    /// [ProcessExt::username]: ./os/linux/trait.ProcessExt.html#tymethod.username
+    #[cfg(target_os = "linux")]
+    #[cfg_attr(docsrs, doc(cfg(target_os = "linux")))]
+    pub fn user<T: Into<String>>(mut self, name: T) -> Finder {
+        self.user = Some(name.into());
+        self
+    }
+
+    /// Matches processes with the `pid` parent process pid.
+    pub fn parent_pid(mut self, pid: Pid) -> Finder {
+        self.parent_pid = Some(pid);
+        self
+    }
+
+    /// Matches processes which were started more than `age` ago.
+    pub fn older_than(mut self, age: Duration) -> Finder {
+        self.older_than = Some(age);
+        self
+    }
+
+    /// Matches processes which were started less than `age` ago.
+    pub fn newer_than(mut self, age: Duration) -> Finder {
+        self.newer_than = Some(age);
+        self
+    }
+
+    /// Returns a stream over the currently running processes matching all the filters.
+    ///
+    /// Only the process attributes required by the filters are loaded.
+    /// Processes terminated during the search or not accessible to check are skipped.
+    ///
+    /// ## Errors
+    ///
+    /// Error is returned if command line pattern is invalid.
+    pub async fn processes(self) -> ProcessResult<impl Stream<Item = ProcessResult<Process>>> {
+        let command = match &self.command {
+            Some(pattern) => Some(Matcher::compile(pattern)?),
+            None => None,
+        };
+        let query = Arc::new(Query {
+            finder: self,
+            command,
+        });
+
+        let stream = snapshots(query.attributes())
+            .await?
+            .try_filter_map(move |(process, info)| {
+                let query = query.clone();
+                async move {
+                    let is_matched = query.matches(&process, info).await?;
+
+                    Ok(if is_matched { Some(process) } else { None })
+                }
+            })
+            .filter(|result| {
+                let skip = matches!(
+                    result,
+                    Err(ProcessError::NoSuchProcess(..))
+                        | Err(ProcessError::ZombieProcess(..))
+                        | Err(ProcessError::AccessDenied(..))
+                );
+
+                future::ready(!skip)
+            });
+
+        Ok(stream)
+    }
+}
+
+/// Finder with a compiled command line pattern.
+#[derive(Debug)]
+struct Query {
+    finder: Finder,
+    command: Option<Matcher>,
+}
+
+impl Query {
+    fn attributes(&self) -> Attributes {
+        let mut attrs = Attributes::empty();
+        attrs.set(Attributes::NAME, self.finder.name.is_some());
+        attrs.set(Attributes::EXE, self.finder.exe.is_some());
+        // Name might be matched by the first command line argument too
+        attrs.set(
+            Attributes::COMMAND,
+            self.command.is_some() || self.finder.name.is_some(),
+        );
+        attrs.set(Attributes::PARENT_PID, self.finder.parent_pid.is_some());
+        attrs.set(
+            Attributes::CREATE_TIME,
+            self.finder.older_than.is_some() || self.finder.newer_than.is_some(),
+        );
+        #[cfg(target_os = "linux")]
+        attrs.set(Attributes::USERNAME, self.finder.user.is_some());
+
+        attrs
+    }
+
+    async fn matches(&self, process: &Process, info: ProcessInfo) -> ProcessResult<bool> {
+        let ProcessInfo {
+            name,
+            exe,
+            command,
+            parent_pid,
+            create_time,
+            #[cfg(target_os = "linux")]
+            username,
+            ..
+        } = info;
+        let command = command.transpose()?;
+
+        if let Some(expected) = self.finder.parent_pid {
+            if parent_pid.transpose()? != Some(expected) {
+                return Ok(false);
+            }
+        }
+        if let (Some(matcher), Some(command)) = (&self.command, &command) {
+            if !matcher.is_match(&command.to_os_string().to_string_lossy()) {
+                return Ok(false);
+            }
+        }
+        if let (Some(expected), Some(exe)) = (&self.finder.exe, exe.transpose()?) {
+            if exe != *expected {
+                return Ok(false);
+            }
+        }
+        if let Some(create_time) = create_time.transpose()? {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs_f64();
+            let age = Duration::from_secs_f64((now - create_time.get::<time::second>()).max(0.0));
+            if matches!(self.finder.older_than, Some(older_than) if age <= older_than) {
+                return Ok(false);
+            }
+            if matches!(self.finder.newer_than, Some(newer_than) if age >= newer_than) {
+                return Ok(false);
+            }
+        }
+        if let (Some(expected), Some(name)) = (&self.finder.name, name.transpose()?) {
+            let is_same_argv0 = command
+                .as_ref()
+                .and_then(|command| command.into_iter().next())
+                == Some(OsStr::new(expected));
+            if !is_same_argv0 && !is_same_name(process, &name, expected).await? {
+                return Ok(false);
+            }
+        }
+        #[cfg(target_os = "linux")]
+        {
+            if let (Some(expected), Some(username)) = (&self.finder.user, username.transpose()?) {
+                if username != *expected {
+                    return Ok(false);
+                }
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// Compares process name with the expected one.
+///
+/// If process name is still truncated (ex. process changed its command line),
+/// executable file name is checked also.
+async fn is_same_name(process: &Process, name: &str, expected: &str) -> ProcessResult<bool> {
+    if name == expected {
+        return Ok(true);
+    }
+    if name.len() < MAX_NAME_LEN || !expected.starts_with(name) {
+        return Ok(false);
+    }
+
+    let exe = process.exe().await?;
+    Ok(exe.file_name() == Some(Path::new(expected).as_os_str()))
+}
+
+/// Returns a new processes search query.
+///
+/// ## Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use heim_common::prelude::*;
+/// # use heim_process::{self as process, ProcessResult};
+/// #
+/// # #[heim_derive::main]
+/// # async fn main() -> ProcessResult<()> {
+/// let mut processes = Box::pin(process::find()
+///     .name("nginx")
+///     .command_regex(r"-c /etc/nginx/\S+\.conf")
+///     .older_than(Duration::from_secs(60))
+///     .processes()
+///     .await?);
+/// while let Some(process) = processes.next().await {
+///     println!("{}", process?.pid());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn find() -> Finder {
+    Finder::default()
+}
@@ -0,0 +1,209 @@
+use std::path::PathBuf;
+
+use heim_common::prelude::*;
+use heim_common::units::Time;
+
+#[cfg(not(target_os = "linux"))]
+use super::processes;
+use super::{Command, CpuTime, Memory, Process, Status};
+use crate::{Pid, ProcessResult};
+
+bitflags::bitflags! {
+    /// Set of the process attributes to load with [Process::snapshot] method
+    /// or [processes_with] function.
+    ///
+    /// [Process::snapshot]: ./struct.Process.html#method.snapshot
+    /// [processes_with]: ./fn.processes_with.html
+    pub struct Attributes: u32 {
+        /// Process parent pid, see [Process::parent_pid](./struct.Process.html#method.parent_pid).
+        const PARENT_PID = 1 << 0;
+        /// Process name, see [Process::name](./struct.Process.html#method.name).
+        const NAME = 1 << 1;
+        /// Process executable, see [Process::exe](./struct.Process.html#method.exe).
+        const EXE = 1 << 2;
+        /// Process command line, see [Process::command](./struct.Process.html#method.command).
+        const COMMAND = 1 << 3;
+        /// Process current working directory, see [Process::cwd](./struct.Process.html#method.cwd).
+        const CWD = 1 << 4;
+        /// Process status, see [Process::status](./struct.Process.html#method.status).
+        const STATUS = 1 << 5;
+        /// Process creation time, see [Process::create_time](./struct.Process.html#method.create_time).
+        const CREATE_TIME = 1 << 6;
+        /// Accumulated process time, see [Process::cpu_time](./struct.Process.html#method.cpu_time).
+        const CPU_TIME = 1 << 7;
+        /// Process memory usage, see [Process::memory](./struct.Process.html#method.memory).
+        const MEMORY = 1 << 8;
+        /// Name of the user owning the process, see
+        /// [ProcessExt::username](./os/linux/trait.ProcessExt.html#tymethod.username).
+        ///
+        /// It is loaded for Linux only.
+        const USERNAME = 1 << 9;
+    }
+}
+
+/// Snapshot of the process attributes.
+///
+/// Each method returns `None` if the corresponding [Attributes] flag was not requested,
+/// otherwise it returns the same result as the corresponding [Process] method would return.
+///
+/// [Attributes]: ./struct.Attributes.html
+/// [Process]: ./struct.Process.html
+#[derive(Debug)]
+pub struct ProcessInfo {
+    pub(crate) pid: Pid,
+    pub(crate) parent_pid: Option<ProcessResult<Pid>>,
+    pub(crate) name: Option<ProcessResult<String>>,
+    pub(crate) exe: Option<ProcessResult<PathBuf>>,
+    pub(crate) command: Option<ProcessResult<Command>>,
+    pub(crate) cwd: Option<ProcessResult<PathBuf>>,
+    pub(crate) status: Option<ProcessResult<Status>>,
+    pub(crate) create_time: Option<ProcessResult<Time>>,
+    pub(crate) cpu_time: Option<ProcessResult<CpuTime>>,
+    pub(crate) memory: Option<ProcessResult<Memory>>,
+    pub(crate) username: Option<ProcessResult<String>>,
+}
+
+impl ProcessInfo {
+    pub(crate) fn new(pid: Pid) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            parent_pid: None,
+            name: None,
+            exe: None,
+            command: None,
+            cwd: None,
+            status: None,
+            create_time: None,
+            cpu_time: None,
+            memory: None,
+            username: None,
+        }
+    }
+
+    /// Returns the process pid.
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    /// Returns the process parent pid.
+    pub fn parent_pid(&self) -> Option<&ProcessResult<Pid>> {
+        self.parent_pid.as_ref()
+    }
+
+    /// Returns the process name.
+    pub fn name(&self) -> Option<&ProcessResult<String>> {
+        self.name.as_ref()
+    }
+
+    /// Returns the process executable as an absolute path.
+    pub fn exe(&self) -> Option<&ProcessResult<PathBuf>> {
+        self.exe.as_ref()
+    }
+
+    /// Returns the process command line.
+    pub fn command(&self) -> Option<&ProcessResult<Command>> {
+        self.command.as_ref()
+    }
+
+    /// Returns the process current working directory.
+    pub fn cwd(&self) -> Option<&ProcessResult<PathBuf>> {
+        self.cwd.as_ref()
+    }
+
+    /// Returns the process status.
+    pub fn status(&self) -> Option<&ProcessResult<Status>> {
+        self.status.as_ref()
+    }
+
+    /// Returns the process creation time, expressed as a [Time] amount since the UNIX epoch.
+    ///
+    /// [Time]: ../units/type.Time.html
+    pub fn create_time(&self) -> Option<&ProcessResult<Time>> {
+        self.create_time.as_ref()
+    }
+
+    /// Returns the accumulated process time.
+    pub fn cpu_time(&self) -> Option<&ProcessResult<CpuTime>> {
+        self.cpu_time.as_ref()
+    }
+
+    /// Returns the process memory usage.
+    pub fn memory(&self) -> Option<&ProcessResult<Memory>> {
+        self.memory.as_ref()
+    }
+
+    /// Returns the name of the user owning the process.
+    ///
+    /// It is always `None` for platforms other than Linux.
+    pub fn username(&self) -> Option<&ProcessResult<String>> {
+        self.username.as_ref()
+    }
+}
+
+/// Loads the requested attributes by calling the corresponding `Process` methods one by one.
+#[cfg(not(target_os = "linux"))]
+pub(crate) async fn load(process: &Process, attrs: Attributes) -> ProcessInfo {
+    let mut info = ProcessInfo::new(process.pid());
+    if attrs.contains(Attributes::PARENT_PID) {
+        info.parent_pid = Some(process.parent_pid().await);
+    }
+    if attrs.contains(Attributes::NAME) {
+        info.name = Some(process.name().await);
+    }
+    if attrs.contains(Attributes::EXE) {
+        info.exe = Some(process.exe().await);
+    }
+    if attrs.contains(Attributes::COMMAND) {
+        info.command = Some(process.command().await);
+    }
+    if attrs.contains(Attributes::CWD) {
+        info.cwd = Some(process.cwd().await);
+    }
+    if attrs.contains(Attributes::STATUS) {
+        info.status = Some(process.status().await);
+    }
+    if attrs.contains(Attributes::CREATE_TIME) {
+        info.create_time = Some(process.create_time().await);
+    }
+    if attrs.contains(Attributes::CPU_TIME) {
+        info.cpu_time = Some(process.cpu_time().await);
+    }
+    if attrs.contains(Attributes::MEMORY) {
+        info.memory = Some(process.memory().await);
+    }
+
+    info
+}
+
+/// Returns a stream over the currently running processes along with their snapshots.
+pub(crate) async fn snapshots(
+    attrs: Attributes,
+) -> Result<impl Stream<Item = ProcessResult<(Process, ProcessInfo)>>> {
+    cfg_if::cfg_if! {
+        if #[cfg(target_os = "linux")] {
+            let inner = crate::sys::snapshots(attrs).await?;
+
+            Ok(inner.map_ok(|(process, info)| (process.into(), info)))
+        } else {
+            let inner = processes().await?;
+
+            Ok(inner.and_then(move |process| async move {
+                let info = process.snapshot(attrs).await;
+
+                Ok((process, info))
+            }))
+        }
+    }
+}
+
+/// Returns a stream over the snapshots of currently running processes,
+/// see [Process::snapshot] method for details.
+///
+/// [Process::snapshot]: ./struct.Process.html#method.snapshot
+pub async fn processes_with(
+    attrs: Attributes,
+) -> Result<impl Stream<Item = ProcessResult<ProcessInfo>>> {
+    let inner = snapshots(attrs).await?;
+
+    Ok(inner.map_ok(|(_, info)| info))
+}
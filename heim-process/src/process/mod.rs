@@ -1,27 +1,38 @@
 use std::fmt;
 use std::path::PathBuf;
+use std::process::ExitStatus;
 use std::time::Instant;
 
 use heim_common::prelude::*;
 use heim_common::units::Time;
 
-use crate::{sys, Pid, ProcessResult};
+use crate::{sys, Pid, ProcessError, ProcessResult};
 
 mod command;
 mod cpu_times;
 mod cpu_usage;
 mod env;
+mod events;
+mod find;
+mod info;
 mod io_counters;
 mod memory;
+mod spawn;
 mod status;
+mod tree;
 
 pub use self::command::{Command, CommandIter};
 pub use self::cpu_times::CpuTime;
 pub use self::cpu_usage::CpuUsage;
 pub use self::env::{Environment, EnvironmentIter, IntoEnvironmentIter};
+pub use self::events::{events, Event};
+pub use self::find::{find, Finder};
+pub use self::info::{processes_with, Attributes, ProcessInfo};
 pub use self::io_counters::IoCounters;
 pub use self::memory::Memory;
+pub use self::spawn::{spawn, Child};
 pub use self::status::Status;
+pub use self::tree::{process_tree, ProcessTree};
 
 /// System process.
 ///
@@ -51,6 +62,90 @@ impl Process {
         get(ppid).await
     }
 
+    /// Returns direct children of this process.
+    ///
+    /// Children terminated while the list is being loaded are skipped.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Linux the `/proc/<pid>/task/<tid>/children` files are used if available
+    /// (kernel should be built with the `CONFIG_PROC_CHILDREN` option),
+    /// for Windows the processes snapshot is used.
+    /// Otherwise, all system processes are scanned for the matching parent pid.
+    pub async fn children(&self) -> ProcessResult<Vec<Process>> {
+        let create_time = self.create_time().await?;
+        let candidates = match self.as_ref().children_pids().await? {
+            Some(pids) => pids,
+            None => self.scan_children_pids().await?,
+        };
+
+        let mut children = Vec::with_capacity(candidates.len());
+        for pid in candidates {
+            let child = match get(pid).await {
+                Ok(child) => child,
+                Err(ProcessError::NoSuchProcess(..)) | Err(ProcessError::ZombieProcess(..)) => {
+                    continue
+                }
+                Err(e) => return Err(e),
+            };
+            // Parent pid might be reused already by the process which started after the child
+            if child.create_time().await? >= create_time {
+                children.push(child);
+            }
+        }
+
+        Ok(children)
+    }
+
+    async fn scan_children_pids(&self) -> ProcessResult<Vec<Pid>> {
+        let mut pids = Vec::new();
+        let mut stream = Box::pin(processes().await?);
+        while let Some(process) = stream.next().await {
+            let process = match process {
+                Ok(process) => process,
+                Err(ProcessError::NoSuchProcess(..)) | Err(ProcessError::AccessDenied(..)) => {
+                    continue
+                }
+                Err(e) => return Err(e),
+            };
+            match process.parent_pid().await {
+                Ok(ppid) if ppid == self.pid() && process.pid() != ppid => pids.push(process.pid()),
+                Ok(..)
+                | Err(ProcessError::NoSuchProcess(..))
+                | Err(ProcessError::AccessDenied(..))
+                | Err(ProcessError::ZombieProcess(..)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(pids)
+    }
+
+    /// Returns all descendants of this process: its children, their children and so on.
+    ///
+    /// Processes are returned in the same breadth-first order as [ProcessTree::descendants] yields.
+    /// They are found in a single [process_tree] pass, so if the whole system processes
+    /// hierarchy is needed, it is cheaper to use that function directly.
+    ///
+    /// [ProcessTree::descendants]: ./struct.ProcessTree.html#method.descendants
+    /// [process_tree]: ./fn.process_tree.html
+    pub async fn descendants(&self) -> ProcessResult<Vec<Process>> {
+        let mut tree = process_tree().await?;
+        match tree.get(self.pid()) {
+            // Process pid is reused already
+            Some(process) if process != self => {
+                return Err(ProcessError::NoSuchProcess(self.pid()))
+            }
+            _ => {}
+        }
+
+        Ok(tree
+            .descendant_pids(self.pid())
+            .into_iter()
+            .filter_map(|pid| tree.remove(pid))
+            .collect())
+    }
+
     /// Returns process name.
     pub async fn name(&self) -> ProcessResult<String> {
         self.as_ref().name().await
@@ -160,6 +255,8 @@ impl Process {
             cpu_count,
             cpu_time,
             at: Instant::now(),
+            #[cfg(target_os = "linux")]
+            with_children: false,
         })
     }
 
@@ -168,6 +265,46 @@ impl Process {
         self.as_ref().memory().await.map(Into::into)
     }
 
+    /// Loads multiple process attributes at once.
+    ///
+    /// Loading errors are kept for each attribute separately,
+    /// see [ProcessInfo] struct.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Linux each required procfs file is read only once,
+    /// while calling the corresponding methods one by one
+    /// might read the same file multiple times (ex. `/proc/<pid>/stat`).
+    ///
+    /// For other platforms it is equal to calling these methods one by one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use heim_process::{self as process, Attributes, ProcessResult};
+    /// #
+    /// # #[heim_derive::main]
+    /// # async fn main() -> ProcessResult<()> {
+    /// let process = process::current().await?;
+    /// let info = process.snapshot(Attributes::NAME | Attributes::STATUS).await;
+    /// if let Some(Ok(name)) = info.name() {
+    ///     println!("Process name: {}", name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [ProcessInfo]: ./struct.ProcessInfo.html
+    pub async fn snapshot(&self, attrs: Attributes) -> ProcessInfo {
+        cfg_if::cfg_if! {
+            if #[cfg(target_os = "linux")] {
+                self.as_ref().snapshot(attrs).await
+            } else {
+                info::load(self, attrs).await
+            }
+        }
+    }
+
     /// Checks if this `Process` is still running.
     pub async fn is_running(&self) -> ProcessResult<bool> {
         self.as_ref().is_running().await
@@ -240,8 +377,17 @@ impl Process {
     ///
     /// ## Returns
     ///
-    /// If the process is already terminated, this method returns `Ok(())`.
-    pub async fn wait(&self) -> ProcessResult<()> {
+    /// If the process is a child of the current one, it is reaped
+    /// and its exit status is returned.
+    ///
+    /// For other processes or if the process is already terminated,
+    /// this method returns `Ok(None)`.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Linux 5.3+ process file descriptor (`pidfd`) is used,
+    /// so the pid reuse can't affect the waiting.
+    pub async fn wait(&self) -> ProcessResult<Option<ExitStatus>> {
         self.as_ref().wait().await
     }
 
@@ -0,0 +1,145 @@
+use std::ops::Deref;
+use std::process::{self, ExitStatus};
+#[cfg(not(unix))]
+use std::{io, time::Duration};
+
+#[cfg(not(unix))]
+use heim_common::prelude::StreamExt as _;
+#[cfg(not(unix))]
+use heim_common::Error;
+use heim_runtime as rt;
+
+#[cfg(unix)]
+use crate::os::unix::ResourceUsage;
+use crate::{Pid, Process, ProcessResult};
+
+/// Child process started by the [spawn] function.
+///
+/// It dereferences into the [Process], so all the process metrics
+/// are available while the child is running.
+///
+/// [Process::wait] should not be used for the child, as it reaps it too,
+/// and the following [Child::wait] call returns the [`NoSuchProcess`] error then.
+///
+/// [spawn]: ./fn.spawn.html
+/// [Process]: ./struct.Process.html
+/// [Process::wait]: ./struct.Process.html#method.wait
+/// [Child::wait]: #method.wait
+/// [`NoSuchProcess`]: ./enum.ProcessError.html#variant.NoSuchProcess
+#[derive(Debug)]
+pub struct Child {
+    child: process::Child,
+    process: Process,
+    status: Option<ExitStatus>,
+    #[cfg(unix)]
+    pub(crate) usage: Option<ResourceUsage>,
+}
+
+impl Child {
+    /// Returns the [Process] matching this child.
+    ///
+    /// [Process]: ./struct.Process.html
+    pub fn process(&self) -> &Process {
+        &self.process
+    }
+
+    /// Returns the underlying `std::process::Child`.
+    pub fn child(&self) -> &process::Child {
+        &self.child
+    }
+
+    /// Returns the mutable reference to the underlying `std::process::Child`,
+    /// which can be used to access the child standard streams.
+    ///
+    /// Child should not be waited for via the returned reference
+    /// or via the [Process::wait] method, otherwise its exit status
+    /// and resource usage will be lost.
+    ///
+    /// [Process::wait]: ./struct.Process.html#method.wait
+    pub fn child_mut(&mut self) -> &mut process::Child {
+        &mut self.child
+    }
+
+    /// Waits for the child termination and returns its exit status.
+    ///
+    /// Child is reaped at this point, so the process metrics are not available anymore.
+    /// Subsequent calls are returning the same exit status.
+    ///
+    /// On Unix systems resource usage of the terminated child is collected too,
+    /// see [ChildExt::resource_usage].
+    ///
+    /// [ChildExt::resource_usage]: ./os/unix/trait.ChildExt.html#tymethod.resource_usage
+    pub async fn wait(&mut self) -> ProcessResult<ExitStatus> {
+        if let Some(status) = self.status {
+            return Ok(status);
+        }
+
+        let status = self.reap().await?;
+        self.status = Some(status);
+
+        Ok(status)
+    }
+
+    #[cfg(unix)]
+    async fn reap(&mut self) -> ProcessResult<ExitStatus> {
+        let (status, usage) = crate::sys::pid_wait4(self.process.pid()).await?;
+        self.usage = Some(usage);
+
+        Ok(status)
+    }
+
+    #[cfg(not(unix))]
+    async fn reap(&mut self) -> ProcessResult<ExitStatus> {
+        // `std::process::Child::wait` blocks and can't be moved into the blocking task,
+        // so the child is polled instead
+        let interval = rt::time::interval(Duration::from_millis(40));
+        futures::pin_mut!(interval);
+        while let Some(..) = interval.next().await {
+            if let Some(status) = self.child.try_wait()? {
+                return Ok(status);
+            }
+        }
+
+        // Same to `pid_wait`, this branch is unreachable, as `time::interval` is infinite
+        let e = Error::from(io::Error::from(io::ErrorKind::Other))
+            .with_message("Unable to await for process termination");
+        Err(e.into())
+    }
+}
+
+impl Deref for Child {
+    type Target = Process;
+
+    fn deref(&self) -> &Process {
+        &self.process
+    }
+}
+
+/// Spawns the `command` as a child process.
+///
+/// Since the child is not reaped until the [Child::wait] call,
+/// its pid can't be reused in between and the returned handle
+/// always points to the spawned process.
+///
+/// [Child::wait]: ./struct.Child.html#method.wait
+pub async fn spawn(mut command: process::Command) -> ProcessResult<Child> {
+    let mut child = command.spawn()?;
+
+    let process = match super::get(child.id() as Pid).await {
+        Ok(process) => process,
+        Err(e) => {
+            // Do not leave the unmanaged child behind
+            let _ = child.kill();
+            let _ = rt::spawn_blocking(move || child.wait()).await;
+            return Err(e);
+        }
+    };
+
+    Ok(Child {
+        child,
+        process,
+        status: None,
+        #[cfg(unix)]
+        usage: None,
+    })
+}
@@ -0,0 +1,170 @@
+use std::collections::{HashMap, VecDeque};
+
+use heim_common::prelude::*;
+
+use super::{processes, Process};
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Snapshot of the processes tree.
+///
+/// See [process_tree] function.
+///
+/// [process_tree]: ./fn.process_tree.html
+#[derive(Debug)]
+pub struct ProcessTree {
+    processes: HashMap<Pid, Process>,
+    parents: HashMap<Pid, Pid>,
+    children: HashMap<Pid, Vec<Pid>>,
+}
+
+impl ProcessTree {
+    /// Returns the process with `pid` given.
+    pub fn get(&self, pid: Pid) -> Option<&Process> {
+        self.processes.get(&pid)
+    }
+
+    /// Returns the number of processes in the tree.
+    pub fn len(&self) -> usize {
+        self.processes.len()
+    }
+
+    /// Returns `true` if there are no processes in the tree.
+    pub fn is_empty(&self) -> bool {
+        self.processes.is_empty()
+    }
+
+    /// Returns an iterator over all processes in the tree in an arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = &Process> {
+        self.processes.values()
+    }
+
+    /// Returns an iterator over the processes without a known parent,
+    /// ordered by their pids.
+    ///
+    /// Usually there is only one root process (`init` for *nix systems),
+    /// but parents of some processes might be not accessible or terminated already.
+    pub fn roots(&self) -> impl Iterator<Item = &Process> {
+        let mut roots = self
+            .processes
+            .keys()
+            .filter(|pid| match self.parents.get(pid) {
+                Some(ppid) => !self.processes.contains_key(ppid),
+                None => true,
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        roots.sort_unstable();
+
+        roots.into_iter().filter_map(move |pid| self.get(pid))
+    }
+
+    /// Returns the parent of the process with `pid` given.
+    pub fn parent(&self, pid: Pid) -> Option<&Process> {
+        self.parents.get(&pid).and_then(|ppid| self.get(*ppid))
+    }
+
+    /// Returns an iterator over the direct children of the process with `pid` given,
+    /// ordered by their pids.
+    pub fn children(&self, pid: Pid) -> impl Iterator<Item = &Process> {
+        self.child_pids(pid)
+            .iter()
+            .filter_map(move |pid| self.get(*pid))
+    }
+
+    /// Returns an iterator over all the descendants of the process with `pid` given.
+    ///
+    /// Processes are yielded in a breadth-first order: children first,
+    /// then grandchildren and so on, each level is ordered by the parent and child pids.
+    pub fn descendants(&self, pid: Pid) -> impl Iterator<Item = &Process> {
+        self.descendant_pids(pid)
+            .into_iter()
+            .filter_map(move |pid| self.get(pid))
+    }
+
+    /// Removes the process with `pid` given from the tree, keeping its relations.
+    pub(crate) fn remove(&mut self, pid: Pid) -> Option<Process> {
+        self.processes.remove(&pid)
+    }
+
+    fn child_pids(&self, pid: Pid) -> &[Pid] {
+        self.children.get(&pid).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub(crate) fn descendant_pids(&self, pid: Pid) -> Vec<Pid> {
+        let mut descendants = Vec::new();
+        let mut queue = self
+            .child_pids(pid)
+            .iter()
+            .copied()
+            .collect::<VecDeque<_>>();
+        while let Some(pid) = queue.pop_front() {
+            descendants.push(pid);
+            queue.extend(self.child_pids(pid));
+        }
+
+        descendants
+    }
+}
+
+/// Checks if the `parent` process was started before its `child`,
+/// otherwise parent pid was reused already by an unrelated process.
+async fn is_started_before(parent: &Process, child: &Process) -> ProcessResult<bool> {
+    match future::try_join(parent.create_time(), child.create_time()).await {
+        Ok((parent, child)) => Ok(parent <= child),
+        Err(ProcessError::NoSuchProcess(..)) | Err(ProcessError::ZombieProcess(..)) => Ok(false),
+        // Relation can't be verified, so it is kept as is
+        Err(ProcessError::AccessDenied(..)) => Ok(true),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns a snapshot of the processes tree,
+/// built from a single pass over the [processes] stream.
+///
+/// Processes which are terminated or not accessible during the pass are skipped.
+/// Process is not linked to its parent if the parent pid is reused already
+/// by the process started after it.
+///
+/// [processes]: ./fn.processes.html
+pub async fn process_tree() -> ProcessResult<ProcessTree> {
+    let mut tree = ProcessTree {
+        processes: HashMap::new(),
+        parents: HashMap::new(),
+        children: HashMap::new(),
+    };
+
+    let mut relations = Vec::new();
+    let mut stream = Box::pin(processes().await?);
+    while let Some(process) = stream.next().await {
+        let process = match process {
+            Ok(process) => process,
+            Err(ProcessError::NoSuchProcess(..)) | Err(ProcessError::AccessDenied(..)) => continue,
+            Err(e) => return Err(e),
+        };
+        let pid = process.pid();
+        match process.parent_pid().await {
+            // Some of the root processes are reported as a parents of themselves
+            Ok(ppid) if ppid != pid => relations.push((pid, ppid)),
+            Ok(..) => {}
+            Err(ProcessError::NoSuchProcess(..)) => continue,
+            Err(ProcessError::AccessDenied(..)) | Err(ProcessError::ZombieProcess(..)) => {}
+            Err(e) => return Err(e),
+        }
+        let _ = tree.processes.insert(pid, process);
+    }
+
+    for (pid, ppid) in relations {
+        if let (Some(process), Some(parent)) = (tree.get(pid), tree.get(ppid)) {
+            if !is_started_before(parent, process).await? {
+                continue;
+            }
+        }
+        let _ = tree.parents.insert(pid, ppid);
+        tree.children.entry(ppid).or_default().push(pid);
+    }
+    for children in tree.children.values_mut() {
+        children.sort_unstable();
+    }
+
+    Ok(tree)
+}
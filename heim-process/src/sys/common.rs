@@ -3,6 +3,10 @@ use ordered_float::NotNan;
 use crate::Pid;
 use heim_common::units::{time, Time};
 
+/// Process name is truncated to this length by some systems,
+/// ex. Linux keeps only the `TASK_COMM_LEN - 1` bytes of it.
+pub(crate) const MAX_NAME_LEN: usize = 15;
+
 /// Process unique ID.
 ///
 /// Processes can't be compared just by their PIDs,
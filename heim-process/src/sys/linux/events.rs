@@ -0,0 +1,404 @@
+//! Process events connector, see `Documentation/driver-api/connector.rst` in the kernel sources.
+
+use std::collections::VecDeque;
+use std::convert::TryInto;
+use std::io;
+use std::mem;
+use std::os::unix::fs::MetadataExt;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::os::unix::process::ExitStatusExt;
+use std::process::ExitStatus;
+use std::time::Duration;
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use crate::{Event, ProcessResult};
+
+const CN_IDX_PROC: u32 = 0x1;
+const CN_VAL_PROC: u32 = 0x1;
+
+const PROC_CN_MCAST_LISTEN: u32 = 1;
+const PROC_CN_MCAST_IGNORE: u32 = 2;
+
+const PROC_EVENT_NONE: u32 = 0x0000_0000;
+const PROC_EVENT_FORK: u32 = 0x0000_0001;
+const PROC_EVENT_EXEC: u32 = 0x0000_0002;
+const PROC_EVENT_EXIT: u32 = 0x8000_0000;
+
+/// Time to wait for the subscription acknowledgement.
+const ACK_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Size of the `struct nlmsghdr`.
+const NLMSG_HDRLEN: usize = 16;
+/// Size of the `struct cn_msg`, excluding the payload.
+const CN_MSG_LEN: usize = 20;
+/// Offset of the `event_data` union in the `struct proc_event`.
+const EVENT_DATA_OFFSET: usize = 16;
+
+/// Inode numbers of the initial namespaces, see `include/linux/proc_ns.h`.
+const PROC_USER_INIT_INO: u64 = 0xEFFF_FFFD;
+const PROC_PID_INIT_INO: u64 = 0xEFFF_FFFC;
+
+#[derive(Debug, PartialEq)]
+enum Message {
+    /// Response to the subscription request.
+    Ack {
+        ack: u32,
+        error: u32,
+    },
+    Event(Event),
+}
+
+#[derive(Debug)]
+struct Socket {
+    fd: RawFd,
+    port: u32,
+    subscribed: bool,
+}
+
+impl Socket {
+    fn open() -> io::Result<Socket> {
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_DGRAM | libc::SOCK_CLOEXEC,
+                libc::NETLINK_CONNECTOR,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let mut socket = Socket {
+            fd,
+            port: 0,
+            subscribed: false,
+        };
+
+        let mut addr: libc::sockaddr_nl = unsafe { mem::zeroed() };
+        addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+        addr.nl_groups = CN_IDX_PROC;
+        let mut len = mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t;
+        let addr_ptr: *mut libc::sockaddr_nl = &mut addr;
+        let result = unsafe { libc::bind(socket.fd, addr_ptr as *const libc::sockaddr, len) };
+        if result != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // Port id is assigned by kernel during the `bind` call
+        let result =
+            unsafe { libc::getsockname(socket.fd, addr_ptr as *mut libc::sockaddr, &mut len) };
+        if result != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        socket.port = addr.nl_pid;
+
+        Ok(socket)
+    }
+
+    fn subscribe(&mut self) -> io::Result<()> {
+        self.send_op(PROC_CN_MCAST_LISTEN)?;
+        self.subscribed = true;
+
+        Ok(())
+    }
+
+    fn send_op(&self, op: u32) -> io::Result<()> {
+        let len = NLMSG_HDRLEN + CN_MSG_LEN + mem::size_of::<u32>();
+        let mut buf = Vec::with_capacity(len);
+        // struct nlmsghdr
+        buf.extend_from_slice(&(len as u32).to_ne_bytes());
+        buf.extend_from_slice(&(libc::NLMSG_DONE as u16).to_ne_bytes());
+        buf.extend_from_slice(&0u16.to_ne_bytes());
+        buf.extend_from_slice(&0u32.to_ne_bytes());
+        buf.extend_from_slice(&self.port.to_ne_bytes());
+        // struct cn_msg
+        buf.extend_from_slice(&CN_IDX_PROC.to_ne_bytes());
+        buf.extend_from_slice(&CN_VAL_PROC.to_ne_bytes());
+        buf.extend_from_slice(&0u32.to_ne_bytes());
+        buf.extend_from_slice(&self.port.to_ne_bytes());
+        buf.extend_from_slice(&(mem::size_of::<u32>() as u16).to_ne_bytes());
+        buf.extend_from_slice(&0u16.to_ne_bytes());
+        // enum proc_cn_mcast_op
+        buf.extend_from_slice(&op.to_ne_bytes());
+
+        let result = unsafe { libc::send(self.fd, buf.as_ptr() as *const libc::c_void, len, 0) };
+        if result < 0 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        let result =
+            unsafe { libc::recv(self.fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len(), 0) };
+        if result < 0 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(result as usize)
+        }
+    }
+}
+
+impl AsRawFd for Socket {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        // Kernel generates events as long as there is at least one subscriber,
+        // but it does not track who they are, so unsubscribing has to be done only once
+        if self.subscribed {
+            let _ = self.send_op(PROC_CN_MCAST_IGNORE);
+        }
+        let _ = unsafe { libc::close(self.fd) };
+    }
+}
+
+#[derive(Debug)]
+struct Listener {
+    socket: rt::io::Async<Socket>,
+    buf: Vec<u8>,
+    pending: VecDeque<Message>,
+}
+
+impl Listener {
+    async fn next(&mut self) -> ProcessResult<Message> {
+        loop {
+            if let Some(message) = self.pending.pop_front() {
+                return Ok(message);
+            }
+
+            let buf = &mut self.buf;
+            let read = self
+                .socket
+                .read_with(|socket| socket.recv(buf))
+                .await
+                .map_err(|e| Error::from(e).with_ffi("recv"))?;
+            parse(&self.buf[..read], &mut self.pending);
+        }
+    }
+
+    /// Waits for the subscription acknowledgement and returns `true` if it was successful.
+    ///
+    /// Events sent before the acknowledgement are skipped,
+    /// as they might be generated before the subscription.
+    async fn wait_ack(&mut self) -> ProcessResult<bool> {
+        let port = self.socket.get_ref().port;
+        loop {
+            match self.next().await? {
+                Message::Ack { ack, error } if ack == port.wrapping_add(1) => return Ok(error == 0),
+                _ => continue,
+            }
+        }
+    }
+}
+
+/// Returns `true` if the current process is in the initial user and PID namespaces,
+/// as the events connector ignores subscription requests from the other namespaces.
+async fn is_initial_namespace() -> bool {
+    let root = rt::linux::procfs_root();
+    let user = root.join("self/ns/user");
+    let pid = root.join("self/ns/pid");
+
+    rt::spawn_blocking(move || {
+        let is_initial = |path, ino| match std::fs::metadata(path) {
+            Ok(metadata) => metadata.ino() == ino,
+            Err(..) => false,
+        };
+
+        is_initial(user, PROC_USER_INIT_INO) && is_initial(pid, PROC_PID_INIT_INO)
+    })
+    .await
+}
+
+/// Subscribes to the process events.
+///
+/// `Ok(None)` is returned if events connector is not available
+/// or there are not enough privileges to use it.
+pub async fn events() -> ProcessResult<Option<impl Stream<Item = ProcessResult<Event>>>> {
+    if !is_initial_namespace().await {
+        return Ok(None);
+    }
+
+    let mut socket = match Socket::open() {
+        Ok(socket) => socket,
+        Err(e) => match e.raw_os_error() {
+            Some(libc::EPERM) | Some(libc::EACCES) | Some(libc::EPROTONOSUPPORT) => {
+                return Ok(None)
+            }
+            _ => return Err(Error::from(e).with_ffi("socket").into()),
+        },
+    };
+    socket
+        .subscribe()
+        .map_err(|e| Error::from(e).with_ffi("send"))?;
+    let socket = rt::io::Async::new(socket)?;
+
+    let mut listener = Listener {
+        socket,
+        buf: vec![0; 8192],
+        pending: VecDeque::new(),
+    };
+    // Kernel without the `CONFIG_PROC_EVENTS` option does not acknowledge subscription at all
+    match rt::time::timeout(ACK_TIMEOUT, listener.wait_ack()).await {
+        Some(Ok(true)) => {}
+        Some(Ok(false)) | None => return Ok(None),
+        Some(Err(e)) => return Err(e),
+    }
+
+    let stream = stream::unfold(listener, |mut listener| async move {
+        loop {
+            match listener.next().await {
+                Ok(Message::Event(event)) => return Some((Ok(event), listener)),
+                // Acknowledgements for the other subscribers
+                Ok(Message::Ack { .. }) => continue,
+                Err(e) => return Some((Err(e), listener)),
+            }
+        }
+    });
+
+    Ok(Some(stream))
+}
+
+fn read_u32(buf: &[u8], offset: usize) -> Option<u32> {
+    let bytes = buf.get(offset..offset + 4)?;
+
+    bytes.try_into().ok().map(u32::from_ne_bytes)
+}
+
+fn read_i32(buf: &[u8], offset: usize) -> Option<i32> {
+    read_u32(buf, offset).map(|value| value as i32)
+}
+
+/// Parses all the netlink messages from the datagram.
+fn parse(mut buf: &[u8], messages: &mut VecDeque<Message>) {
+    while let Some(len) = read_u32(buf, 0) {
+        let len = len as usize;
+        if len < NLMSG_HDRLEN || len > buf.len() {
+            break;
+        }
+        let kind = buf
+            .get(4..6)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u16::from_ne_bytes);
+        if kind == Some(libc::NLMSG_DONE as u16) {
+            if let Some(message) = parse_message(&buf[NLMSG_HDRLEN..len]) {
+                messages.push_back(message);
+            }
+        }
+
+        // Messages are aligned to the 4 bytes boundary
+        let aligned = (len + 3) & !3;
+        buf = buf.get(aligned..).unwrap_or(&[]);
+    }
+}
+
+/// Parses `struct cn_msg` with the `struct proc_event` payload.
+///
+/// Threads creation and termination events are skipped.
+fn parse_message(msg: &[u8]) -> Option<Message> {
+    if read_u32(msg, 0)? != CN_IDX_PROC || read_u32(msg, 4)? != CN_VAL_PROC {
+        return None;
+    }
+    let ack = read_u32(msg, 12)?;
+    let event = msg.get(CN_MSG_LEN..)?;
+    let data = event.get(EVENT_DATA_OFFSET..)?;
+
+    let message = match read_u32(event, 0)? {
+        PROC_EVENT_NONE => Message::Ack {
+            ack,
+            error: read_u32(data, 0)?,
+        },
+        PROC_EVENT_FORK => {
+            let parent = read_i32(data, 4)?;
+            let child_pid = read_i32(data, 8)?;
+            let child = read_i32(data, 12)?;
+            if child_pid != child {
+                return None;
+            }
+
+            Message::Event(Event::Fork { parent, child })
+        }
+        PROC_EVENT_EXEC => Message::Event(Event::Exec {
+            pid: read_i32(data, 4)?,
+        }),
+        PROC_EVENT_EXIT => {
+            let pid = read_i32(data, 0)?;
+            if pid != read_i32(data, 4)? {
+                return None;
+            }
+            let exit_code = read_i32(data, 8)?;
+
+            Message::Event(Event::Exit {
+                pid,
+                status: Some(ExitStatus::from_raw(exit_code)),
+            })
+        }
+        _ => return None,
+    };
+
+    Some(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::ExitStatus;
+
+    use super::{parse, Message, CN_IDX_PROC, CN_VAL_PROC, PROC_EVENT_EXIT, PROC_EVENT_FORK};
+    use crate::Event;
+
+    fn message(what: u32, data: &[i32]) -> Vec<u8> {
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&what.to_ne_bytes());
+        payload.extend_from_slice(&0u32.to_ne_bytes());
+        payload.extend_from_slice(&0u64.to_ne_bytes());
+        for value in data {
+            payload.extend_from_slice(&value.to_ne_bytes());
+        }
+
+        let len = 16 + 20 + payload.len();
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&(len as u32).to_ne_bytes());
+        buf.extend_from_slice(&(libc::NLMSG_DONE as u16).to_ne_bytes());
+        buf.extend_from_slice(&[0; 10]);
+        buf.extend_from_slice(&CN_IDX_PROC.to_ne_bytes());
+        buf.extend_from_slice(&CN_VAL_PROC.to_ne_bytes());
+        buf.extend_from_slice(&[0; 8]);
+        buf.extend_from_slice(&(payload.len() as u16).to_ne_bytes());
+        buf.extend_from_slice(&[0; 2]);
+        buf.extend_from_slice(&payload);
+
+        buf
+    }
+
+    #[test]
+    fn test_parse() {
+        let mut buf = message(PROC_EVENT_FORK, &[10, 10, 20, 20]);
+        // Thread creation
+        buf.extend(message(PROC_EVENT_FORK, &[20, 20, 21, 20]));
+        buf.extend(message(PROC_EVENT_EXIT, &[20, 20, 256, 0, 10, 10]));
+
+        let mut messages = VecDeque::new();
+        parse(&buf, &mut messages);
+
+        assert_eq!(
+            messages,
+            vec![
+                Message::Event(Event::Fork {
+                    parent: 10,
+                    child: 20
+                }),
+                Message::Event(Event::Exit {
+                    pid: 20,
+                    status: Some(ExitStatus::from_raw(256)),
+                }),
+            ]
+        );
+    }
+}
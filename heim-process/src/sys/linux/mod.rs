@@ -1,6 +1,9 @@
+mod events;
 mod pids;
 mod process;
+mod taskstats;
 
+pub use self::events::events;
 pub use self::pids::*;
 pub use self::process::*;
-pub use crate::sys::unix::{Environment, EnvironmentIter, IntoEnvironmentIter};
+pub use crate::sys::unix::{pid_wait4, Environment, EnvironmentIter, IntoEnvironmentIter};
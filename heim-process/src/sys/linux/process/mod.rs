@@ -3,26 +3,44 @@ use std::hash;
 use std::io;
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+use std::sync::{Arc, Mutex};
 
 use heim_common::prelude::*;
 use heim_common::units::Time;
 use heim_runtime as rt;
 
+use super::taskstats::taskstats;
 use super::{pid_exists, pids};
-use crate::os::unix::Signal;
-use crate::sys::common::UniqueId;
+use crate::os::linux::{
+    Capabilities, CapabilitySet, Cgroup, CgroupStats, Connection, ConnectionKind, CpuSet,
+    CtxSwitches, DelayAccounting, FullMemory, Gids, IoPriority, MemoryMap, NamespaceKind,
+    Namespaces, OpenFile, PageFaults, SchedPolicy, Scheduler, Seccomp, StackFrame, Syscall, Thread,
+    Uids,
+};
+use crate::os::unix::{Limit, Limits, Resource, Signal};
+use crate::sys::common::{UniqueId, MAX_NAME_LEN};
 use crate::sys::linux::process::procfs::process_file_path;
-use crate::sys::unix::{pid_kill, pid_priority, pid_setpriority, pid_wait};
-use crate::{Pid, ProcessError, ProcessResult, Status};
+use crate::sys::unix::{
+    pid_getaffinity, pid_ioprio_get, pid_ioprio_set, pid_kill, pid_priority, pid_prlimit,
+    pid_setaffinity, pid_setpriority, pid_wait, username,
+};
+use crate::{Attributes, Pid, ProcessError, ProcessInfo, ProcessResult, Status};
 
+mod pidfd;
 mod procfs;
+mod terminal;
 
+use self::pidfd::PidFd;
 pub use self::procfs::{Command, CommandIter, CpuTime, Environment, IoCounters, Memory};
 
 #[derive(Debug)]
 pub struct Process {
     pid: Pid,
     unique_id: UniqueId,
+    // Opened lazily on the first use,
+    // as keeping file descriptor for each loaded process might exhaust the fds limit
+    pidfd: Mutex<Option<Arc<PidFd>>>,
 }
 
 impl Process {
@@ -36,32 +54,18 @@ impl Process {
         Ok(ppid)
     }
 
+    pub async fn children_pids(&self) -> ProcessResult<Option<Vec<Pid>>> {
+        procfs::children(self.pid).await
+    }
+
     pub async fn name(&self) -> ProcessResult<String> {
         let procfs::Stat { name, .. } = procfs::stat(self.pid).await?;
 
-        // TODO: Move `15` to the const
-        if name.len() >= 15 {
-            let command = match procfs::command(self.pid).await {
-                Ok(command) => command,
+        if is_truncated(&name) {
+            match procfs::command(self.pid).await {
+                Ok(command) => Ok(full_name(name, &command)),
                 // Reading process command might fail, so we should better fall back to what we got
-                Err(..) => return Ok(name),
-            };
-
-            // There might be an absolute path to executable
-            let path = command
-                .into_iter()
-                .next()
-                .map(Path::new)
-                .and_then(Path::file_name);
-
-            match path {
-                // We can assume that on Linux paths and filenames are UTF-8,
-                // and since OsStr does not has the `starts_with` method,
-                // we could compare raw bytes
-                Some(exe) if exe.as_bytes().starts_with(name.as_bytes()) => {
-                    Ok(exe.to_string_lossy().into_owned())
-                }
-                _ => Ok(name),
+                Err(..) => Ok(name),
             }
         } else {
             Ok(name)
@@ -126,6 +130,83 @@ impl Process {
         procfs::stat_memory(self.pid).await
     }
 
+    fn new(pid: Pid, create_time: Time) -> Process {
+        Process {
+            pid,
+            unique_id: UniqueId::new(pid, create_time),
+            pidfd: Mutex::new(None),
+        }
+    }
+
+    pub async fn snapshot(&self, attrs: Attributes) -> ProcessInfo {
+        let stat = if attrs.intersects(STAT_ATTRIBUTES) {
+            Some(procfs::stat(self.pid).await)
+        } else {
+            None
+        };
+
+        self.snapshot_with(attrs, stat).await
+    }
+
+    /// Loads the snapshot, reusing the `/proc/<pid>/stat` file contents if they were read already.
+    async fn snapshot_with(
+        &self,
+        attrs: Attributes,
+        stat: Option<ProcessResult<procfs::Stat>>,
+    ) -> ProcessInfo {
+        let mut info = ProcessInfo::new(self.pid);
+
+        let is_name_truncated = match &stat {
+            Some(Ok(stat)) => attrs.contains(Attributes::NAME) && is_truncated(&stat.name),
+            _ => false,
+        };
+        let command = if attrs.contains(Attributes::COMMAND) || is_name_truncated {
+            Some(procfs::command(self.pid).await)
+        } else {
+            None
+        };
+
+        if let Some(stat) = &stat {
+            if attrs.contains(Attributes::PARENT_PID) {
+                info.parent_pid = Some(stat_field(stat, |stat| stat.ppid));
+            }
+            if attrs.contains(Attributes::NAME) {
+                info.name = Some(stat_field(stat, |stat| match &command {
+                    Some(Ok(command)) if is_truncated(&stat.name) => {
+                        full_name(stat.name.clone(), command)
+                    }
+                    _ => stat.name.clone(),
+                }));
+            }
+            if attrs.contains(Attributes::STATUS) {
+                info.status = Some(stat_field(stat, |stat| stat.state));
+            }
+        }
+        if attrs.contains(Attributes::CPU_TIME) {
+            info.cpu_time = stat.map(|stat| stat.map(|stat| CpuTime::from(stat).into()));
+        }
+        if attrs.contains(Attributes::COMMAND) {
+            info.command = command.map(|command| command.map(Into::into));
+        }
+        if attrs.contains(Attributes::EXE) {
+            info.exe = Some(self.exe().await);
+        }
+        if attrs.contains(Attributes::CWD) {
+            info.cwd = Some(self.cwd().await);
+        }
+        if attrs.contains(Attributes::CREATE_TIME) {
+            info.create_time = Some(self.create_time().await);
+        }
+        if attrs.contains(Attributes::MEMORY) {
+            info.memory = Some(self.memory().await.map(Into::into));
+        }
+        if attrs.contains(Attributes::USERNAME) {
+            info.username = Some(self.username().await);
+        }
+
+        info
+    }
+
     pub async fn niceness(&self) -> ProcessResult<i32> {
         pid_priority(self.pid)
     }
@@ -134,14 +215,44 @@ impl Process {
         pid_setpriority(self.pid, value)
     }
 
+    pub async fn rlimits(&self) -> ProcessResult<Limits> {
+        procfs::limits(self.pid).await
+    }
+
+    pub async fn set_rlimit(&self, resource: Resource, limit: Limit) -> ProcessResult<()> {
+        pid_prlimit(self.pid, resource, limit)
+    }
+
     pub async fn is_running(&self) -> ProcessResult<bool> {
         let other = get(self.pid).await?;
 
         Ok(other == *self)
     }
 
+    /// Returns the process file descriptor or `None` if kernel does not support them.
+    async fn pidfd(&self) -> ProcessResult<Option<Arc<PidFd>>> {
+        if let Some(pidfd) = &*self.pidfd.lock().expect("Poisoned pidfd lock") {
+            return Ok(Some(pidfd.clone()));
+        }
+
+        let pidfd = match PidFd::open(self.pid)? {
+            Some(pidfd) => Arc::new(pidfd),
+            None => return Ok(None),
+        };
+        // Pid might be reused already by the time file descriptor was opened,
+        // but if it is still the same process, descriptor will refer to it forever
+        if !self.is_running().await? {
+            return Err(ProcessError::NoSuchProcess(self.pid));
+        }
+
+        let mut cached = self.pidfd.lock().expect("Poisoned pidfd lock");
+        Ok(Some(cached.get_or_insert(pidfd).clone()))
+    }
+
     pub async fn _signal(&self, signal: Signal) -> ProcessResult<()> {
-        if self.is_running().await? {
+        if let Some(pidfd) = self.pidfd().await? {
+            pidfd.send_signal(signal)
+        } else if self.is_running().await? {
             pid_kill(self.pid, signal)
         } else {
             Err(ProcessError::NoSuchProcess(self.pid))
@@ -168,8 +279,14 @@ impl Process {
         self.signal(Signal::Kill).await
     }
 
-    pub async fn wait(&self) -> ProcessResult<()> {
-        pid_wait(self.pid).await
+    pub async fn wait(&self) -> ProcessResult<Option<ExitStatus>> {
+        match self.pidfd().await {
+            Ok(Some(pidfd)) => pidfd.wait().await,
+            Ok(None) => pid_wait(self.pid).await,
+            // Same to `pid_wait`, terminated process is not an error
+            Err(ProcessError::NoSuchProcess(..)) => Ok(None),
+            Err(e) => Err(e),
+        }
     }
 
     // Linux-specific methods
@@ -195,6 +312,443 @@ impl Process {
 
         Ok(stream.map_err(Into::into).boxed())
     }
+
+    pub async fn threads(&self) -> ProcessResult<BoxStream<'_, ProcessResult<Thread>>> {
+        let pid = self.pid;
+        let stream = procfs::tids(pid)
+            .await?
+            .try_filter_map(move |tid| async move {
+                match procfs::task_stat(pid, tid).await {
+                    Ok(stat) => Ok(Some(Thread::from_stat(stat))),
+                    // Thread was terminated after the `task/` directory listing
+                    Err(ProcessError::NoSuchProcess(..)) => Ok(None),
+                    Err(e) => Err(e),
+                }
+            });
+
+        Ok(stream.boxed())
+    }
+
+    pub async fn open_files(&self) -> ProcessResult<BoxStream<'_, ProcessResult<OpenFile>>> {
+        let files = procfs::open_files(self.pid).await?;
+
+        Ok(stream::iter(files).map(Ok).boxed())
+    }
+
+    pub async fn num_fds(&self) -> ProcessResult<usize> {
+        procfs::num_fds(self.pid).await
+    }
+
+    pub async fn connections(
+        &self,
+        kind: ConnectionKind,
+    ) -> ProcessResult<BoxStream<'_, ProcessResult<Connection>>> {
+        let connections = procfs::connections(self.pid, kind).await?;
+
+        Ok(stream::iter(connections).map(Ok).boxed())
+    }
+
+    pub async fn memory_full_info(&self) -> ProcessResult<FullMemory> {
+        procfs::memory_full_info(self.pid).await
+    }
+
+    pub async fn memory_maps(&self) -> ProcessResult<BoxStream<'_, ProcessResult<MemoryMap>>> {
+        let maps = procfs::memory_maps(self.pid).await?;
+
+        Ok(stream::iter(maps).map(Ok).boxed())
+    }
+
+    pub async fn uids(&self) -> ProcessResult<Uids> {
+        let procfs::StatusFile { uid, .. } = procfs::status(self.pid).await?;
+        let [real, effective, saved, filesystem] =
+            uid.ok_or_else(|| procfs::StatusFile::missing_key("Uid"))?;
+
+        Ok(Uids {
+            real,
+            effective,
+            saved,
+            filesystem,
+        })
+    }
+
+    pub async fn gids(&self) -> ProcessResult<Gids> {
+        let procfs::StatusFile { gid, .. } = procfs::status(self.pid).await?;
+        let [real, effective, saved, filesystem] =
+            gid.ok_or_else(|| procfs::StatusFile::missing_key("Gid"))?;
+
+        Ok(Gids {
+            real,
+            effective,
+            saved,
+            filesystem,
+        })
+    }
+
+    pub async fn groups(&self) -> ProcessResult<Vec<libc::gid_t>> {
+        let procfs::StatusFile { groups, .. } = procfs::status(self.pid).await?;
+
+        groups
+            .ok_or_else(|| procfs::StatusFile::missing_key("Groups"))
+            .map_err(Into::into)
+    }
+
+    pub async fn username(&self) -> ProcessResult<String> {
+        let uid = self.uids().await?.real();
+        let name = rt::spawn_blocking(move || username(uid)).await?;
+
+        // Same to `ps`, falling back to the numeric representation
+        // if there is no such user in passwd database
+        Ok(name.unwrap_or_else(|| uid.to_string()))
+    }
+
+    pub async fn capabilities(&self) -> ProcessResult<Capabilities> {
+        let status = procfs::status(self.pid).await?;
+        let set = |value: Option<u64>, key| {
+            value
+                .map(CapabilitySet)
+                .ok_or_else(|| procfs::StatusFile::missing_key(key))
+        };
+
+        Ok(Capabilities {
+            inheritable: set(status.cap_inh, "CapInh")?,
+            permitted: set(status.cap_prm, "CapPrm")?,
+            effective: set(status.cap_eff, "CapEff")?,
+            bounding: set(status.cap_bnd, "CapBnd")?,
+            ambient: status.cap_amb.map(CapabilitySet),
+        })
+    }
+
+    pub async fn seccomp(&self) -> ProcessResult<Seccomp> {
+        let procfs::StatusFile { seccomp, .. } = procfs::status(self.pid).await?;
+
+        match seccomp {
+            Some(value) => Seccomp::try_from_str(&value).map_err(Into::into),
+            None => Err(procfs::StatusFile::missing_key("Seccomp").into()),
+        }
+    }
+
+    pub async fn no_new_privs(&self) -> ProcessResult<bool> {
+        let procfs::StatusFile { no_new_privs, .. } = procfs::status(self.pid).await?;
+
+        no_new_privs
+            .ok_or_else(|| procfs::StatusFile::missing_key("NoNewPrivs"))
+            .map_err(Into::into)
+    }
+
+    pub async fn speculation_store_bypass(&self) -> ProcessResult<String> {
+        let procfs::StatusFile {
+            speculation_store_bypass,
+            ..
+        } = procfs::status(self.pid).await?;
+
+        speculation_store_bypass
+            .ok_or_else(|| procfs::StatusFile::missing_key("Speculation_Store_Bypass"))
+            .map_err(Into::into)
+    }
+
+    pub async fn namespaces(&self) -> ProcessResult<Namespaces> {
+        procfs::namespaces(self.pid).await
+    }
+
+    pub fn resolve_path(&self, path: &Path) -> PathBuf {
+        // Relative paths are resolved from the process working directory
+        match path.strip_prefix("/") {
+            Ok(relative) => process_file_path(self.pid, "root").join(relative),
+            Err(..) => process_file_path(self.pid, "cwd").join(path),
+        }
+    }
+
+    pub async fn ns_pids(&self) -> ProcessResult<Vec<Pid>> {
+        let procfs::StatusFile { ns_pids, .. } = procfs::status(self.pid).await?;
+
+        ns_pids
+            .ok_or_else(|| procfs::StatusFile::missing_key("NSpid"))
+            .map_err(Into::into)
+    }
+
+    pub async fn ns_process(&self, pid: Pid) -> ProcessResult<Process> {
+        let (ns_pids, namespaces) = future::try_join(self.ns_pids(), self.namespaces()).await?;
+        // This process is in the same PID namespace as the procfs is
+        if ns_pids.len() < 2 {
+            return get(pid).await;
+        }
+        let namespace = namespaces.get(NamespaceKind::Pid);
+
+        let mut candidates = Box::pin(pids().await?);
+        while let Some(candidate) = candidates.next().await {
+            let candidate = candidate?;
+            let candidate_ns_pids = match procfs::status(candidate).await {
+                Ok(procfs::StatusFile {
+                    ns_pids: Some(ns_pids),
+                    ..
+                }) => ns_pids,
+                // `NSpid` key is missing if the candidate is exiting already
+                Ok(..)
+                | Err(ProcessError::NoSuchProcess(..))
+                | Err(ProcessError::AccessDenied(..)) => continue,
+                Err(e) => return Err(e),
+            };
+            if candidate_ns_pids.len() != ns_pids.len() || candidate_ns_pids.last() != Some(&pid) {
+                continue;
+            }
+
+            // Sibling namespaces on the same nesting level might have the same pids
+            match procfs::namespaces(candidate).await {
+                Ok(candidate_namespaces)
+                    if candidate_namespaces.get(NamespaceKind::Pid) == namespace =>
+                {
+                    return get(candidate).await
+                }
+                Ok(..)
+                | Err(ProcessError::NoSuchProcess(..))
+                | Err(ProcessError::AccessDenied(..)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(ProcessError::NoSuchProcess(pid))
+    }
+
+    pub async fn cgroups(&self) -> ProcessResult<Vec<Cgroup>> {
+        procfs::cgroups(self.pid).await
+    }
+
+    pub async fn cgroup_stats(&self) -> ProcessResult<Option<CgroupStats>> {
+        let directory = self
+            .cgroups()
+            .await?
+            .into_iter()
+            .find(Cgroup::is_unified)
+            .and_then(|cgroup| cgroup.directory);
+
+        match directory {
+            Some(directory) => Ok(Some(procfs::cgroup_stats(directory).await?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn cpu_affinity(&self) -> ProcessResult<CpuSet> {
+        pid_getaffinity(self.pid)
+    }
+
+    pub async fn set_cpu_affinity(&self, cpus: &CpuSet) -> ProcessResult<()> {
+        let cpu_count = heim_cpu::logical_count().await?;
+        if !cpus.is_valid_for(cpu_count) {
+            let e =
+                Error::from(io::Error::from(io::ErrorKind::InvalidInput)).with_message(format!(
+                    "CPU set should not be empty and should contain CPUs below {}",
+                    cpu_count
+                ));
+            return Err(e.into());
+        }
+
+        pid_setaffinity(self.pid, cpus)
+    }
+
+    pub async fn cpus_allowed(&self) -> ProcessResult<CpuSet> {
+        let procfs::StatusFile {
+            cpus_allowed_list, ..
+        } = procfs::status(self.pid).await?;
+
+        cpus_allowed_list
+            .map(|cpus| cpus.into_iter().collect())
+            .ok_or_else(|| procfs::StatusFile::missing_key("Cpus_allowed_list"))
+            .map_err(Into::into)
+    }
+
+    pub async fn mems_allowed(&self) -> ProcessResult<Vec<usize>> {
+        let procfs::StatusFile {
+            mems_allowed_list, ..
+        } = procfs::status(self.pid).await?;
+
+        mems_allowed_list
+            .ok_or_else(|| procfs::StatusFile::missing_key("Mems_allowed_list"))
+            .map_err(Into::into)
+    }
+
+    pub async fn ionice(&self) -> ProcessResult<IoPriority> {
+        pid_ioprio_get(self.pid)
+    }
+
+    pub async fn set_ionice(&self, priority: IoPriority) -> ProcessResult<()> {
+        pid_ioprio_set(self.pid, priority)
+    }
+
+    pub async fn page_faults(&self) -> ProcessResult<PageFaults> {
+        let procfs::Stat {
+            minflt,
+            cminflt,
+            majflt,
+            cmajflt,
+            ..
+        } = procfs::stat(self.pid).await?;
+
+        Ok(PageFaults {
+            minor: minflt,
+            major: majflt,
+            children_minor: cminflt,
+            children_major: cmajflt,
+        })
+    }
+
+    pub async fn num_ctx_switches(&self) -> ProcessResult<CtxSwitches> {
+        let procfs::StatusFile {
+            voluntary_ctxt_switches,
+            nonvoluntary_ctxt_switches,
+            ..
+        } = procfs::status(self.pid).await?;
+
+        Ok(CtxSwitches {
+            voluntary: voluntary_ctxt_switches
+                .ok_or_else(|| procfs::StatusFile::missing_key("voluntary_ctxt_switches"))?,
+            involuntary: nonvoluntary_ctxt_switches
+                .ok_or_else(|| procfs::StatusFile::missing_key("nonvoluntary_ctxt_switches"))?,
+        })
+    }
+
+    pub async fn num_threads(&self) -> ProcessResult<u64> {
+        let procfs::Stat { num_threads, .. } = procfs::stat(self.pid).await?;
+
+        Ok(num_threads)
+    }
+
+    pub async fn process_group(&self) -> ProcessResult<Pid> {
+        let procfs::Stat { pgrp, .. } = procfs::stat(self.pid).await?;
+
+        Ok(pgrp)
+    }
+
+    pub async fn session_id(&self) -> ProcessResult<Pid> {
+        let procfs::Stat { session_id, .. } = procfs::stat(self.pid).await?;
+
+        Ok(session_id)
+    }
+
+    pub async fn terminal(&self) -> ProcessResult<Option<PathBuf>> {
+        let procfs::Stat { tty_nr, .. } = procfs::stat(self.pid).await?;
+
+        Ok(terminal::terminal(tty_nr).await)
+    }
+
+    pub async fn terminal_process_group(&self) -> ProcessResult<Option<Pid>> {
+        let procfs::Stat { tpgid, .. } = procfs::stat(self.pid).await?;
+
+        // `-1` stands for the missing controlling terminal
+        if tpgid > 0 {
+            Ok(Some(tpgid))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub async fn scheduler(&self) -> ProcessResult<Scheduler> {
+        let procfs::Stat {
+            policy,
+            rt_priority,
+            ..
+        } = procfs::stat(self.pid).await?;
+        let stat = procfs::schedstat(self.pid).await?;
+
+        Ok(Scheduler {
+            policy: SchedPolicy::from_u32(policy),
+            rt_priority,
+            stat,
+        })
+    }
+
+    pub async fn delay_accounting(&self) -> ProcessResult<DelayAccounting> {
+        let procfs::Stat { blkio_delay, .. } = procfs::stat(self.pid).await?;
+        let cpu_delay = procfs::schedstat(self.pid)
+            .await?
+            .map(|stat| stat.wait_time());
+        let taskstats = taskstats(self.pid).await?;
+
+        Ok(DelayAccounting {
+            cpu_delay,
+            blkio_delay,
+            taskstats,
+        })
+    }
+
+    pub async fn wchan(&self) -> ProcessResult<Option<String>> {
+        procfs::wchan(self.pid).await
+    }
+
+    pub async fn kernel_stack(&self) -> ProcessResult<Vec<StackFrame>> {
+        procfs::stack(self.pid).await
+    }
+
+    pub async fn syscall(&self) -> ProcessResult<Option<Syscall>> {
+        procfs::syscall(self.pid).await
+    }
+
+    pub async fn oom_score(&self) -> ProcessResult<u32> {
+        procfs::oom_score(self.pid).await
+    }
+
+    pub async fn oom_score_adj(&self) -> ProcessResult<i32> {
+        procfs::oom_score_adj(self.pid).await
+    }
+
+    pub async fn set_oom_score_adj(&self, value: i32) -> ProcessResult<()> {
+        if !(OOM_SCORE_ADJ_MIN..=OOM_SCORE_ADJ_MAX).contains(&value) {
+            let e =
+                Error::from(io::Error::from(io::ErrorKind::InvalidInput)).with_message(format!(
+                    "OOM score adjustment {} is out of the [{}, {}] range",
+                    value, OOM_SCORE_ADJ_MIN, OOM_SCORE_ADJ_MAX
+                ));
+            return Err(e.into());
+        }
+
+        procfs::set_oom_score_adj(self.pid, value).await
+    }
+}
+
+/// Attributes loaded from the `/proc/<pid>/stat` file.
+const STAT_ATTRIBUTES: Attributes = Attributes::from_bits_truncate(
+    Attributes::PARENT_PID.bits()
+        | Attributes::NAME.bits()
+        | Attributes::STATUS.bits()
+        | Attributes::CPU_TIME.bits(),
+);
+
+/// Range of the `/proc/<pid>/oom_score_adj` values, see `include/uapi/linux/oom.h`.
+const OOM_SCORE_ADJ_MIN: i32 = -1000;
+const OOM_SCORE_ADJ_MAX: i32 = 1000;
+
+fn is_truncated(name: &str) -> bool {
+    name.len() >= MAX_NAME_LEN
+}
+
+/// Restores the truncated process name from its command line.
+fn full_name(name: String, command: &Command) -> String {
+    // There might be an absolute path to executable
+    let path = command
+        .into_iter()
+        .next()
+        .map(Path::new)
+        .and_then(Path::file_name);
+
+    match path {
+        // We can assume that on Linux paths and filenames are UTF-8,
+        // and since OsStr does not has the `starts_with` method,
+        // we could compare raw bytes
+        Some(exe) if exe.as_bytes().starts_with(name.as_bytes()) => {
+            exe.to_string_lossy().into_owned()
+        }
+        _ => name,
+    }
+}
+
+/// Extracts the value from the shared `/proc/<pid>/stat` file contents.
+fn stat_field<T, F>(stat: &ProcessResult<procfs::Stat>, project: F) -> ProcessResult<T>
+where
+    F: FnOnce(&procfs::Stat) -> T,
+{
+    match stat {
+        Ok(stat) => Ok(project(stat)),
+        Err(e) => Err(e.duplicate()),
+    }
 }
 
 impl hash::Hash for Process {
@@ -218,13 +772,33 @@ pub async fn processes() -> Result<impl Stream<Item = ProcessResult<Process>>> {
     Ok(stream)
 }
 
+/// Loads the processes along with their snapshots,
+/// sharing the same `/proc/<pid>/stat` read between them.
+pub async fn snapshots(
+    attrs: Attributes,
+) -> Result<impl Stream<Item = ProcessResult<(Process, ProcessInfo)>>> {
+    let pids = pids().await?;
+
+    let stream = pids.map_err(Into::into).and_then(move |pid| async move {
+        let stat = procfs::stat(pid).await?;
+        let process = Process::new(pid, stat.create_time);
+        let stat = if attrs.intersects(STAT_ATTRIBUTES) {
+            Some(Ok(stat))
+        } else {
+            None
+        };
+
+        let info = process.snapshot_with(attrs, stat).await;
+
+        Ok((process, info))
+    });
+    Ok(stream)
+}
+
 pub async fn get(pid: Pid) -> ProcessResult<Process> {
     let procfs::Stat { create_time, .. } = procfs::stat(pid).await?;
 
-    Ok(Process {
-        pid,
-        unique_id: UniqueId::new(pid, create_time),
-    })
+    Ok(Process::new(pid, create_time))
 }
 
 pub async fn current() -> ProcessResult<Process> {
@@ -0,0 +1,158 @@
+//! Process file descriptors, available since Linux 5.3.
+//!
+//! Unlike pids, file descriptor is always referring to the same process,
+//! even if it was terminated and its pid was reused by the other one.
+
+use std::io;
+use std::mem;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::os::unix::process::ExitStatusExt;
+use std::process::ExitStatus;
+use std::ptr;
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use crate::os::unix::Signal;
+use crate::{Pid, ProcessError, ProcessResult};
+
+#[derive(Debug)]
+pub struct PidFd {
+    pid: Pid,
+    fd: RawFd,
+}
+
+impl PidFd {
+    /// Opens file descriptor for the `pid` process.
+    ///
+    /// `Ok(None)` is returned if kernel does not support the `pidfd_open` syscall.
+    pub fn open(pid: Pid) -> ProcessResult<Option<PidFd>> {
+        let result = unsafe { libc::syscall(libc::SYS_pidfd_open, pid, 0) };
+        if result >= 0 {
+            return Ok(Some(PidFd {
+                pid,
+                fd: result as RawFd,
+            }));
+        }
+
+        let e = io::Error::last_os_error();
+        match e.raw_os_error() {
+            Some(libc::ENOSYS) => Ok(None),
+            Some(libc::ESRCH) => Err(ProcessError::NoSuchProcess(pid)),
+            _ => Err(Error::from(e).with_ffi("pidfd_open").into()),
+        }
+    }
+
+    pub fn send_signal(&self, signal: Signal) -> ProcessResult<()> {
+        let signal: libc::c_int = signal.into();
+        let result = unsafe {
+            libc::syscall(
+                libc::SYS_pidfd_send_signal,
+                self.fd,
+                signal,
+                ptr::null::<libc::siginfo_t>(),
+                0,
+            )
+        };
+        if result == 0 {
+            return Ok(());
+        }
+
+        let e = io::Error::last_os_error();
+        match e.raw_os_error() {
+            Some(libc::ESRCH) => Err(ProcessError::NoSuchProcess(self.pid)),
+            Some(libc::EPERM) => Err(ProcessError::AccessDenied(self.pid)),
+            _ => Err(Error::from(e).with_ffi("pidfd_send_signal").into()),
+        }
+    }
+
+    /// Waits until the process is terminated.
+    ///
+    /// If it is a child of the current process, it is reaped and its exit status is returned.
+    pub async fn wait(&self) -> ProcessResult<Option<ExitStatus>> {
+        // Each waiter registers its own duplicate in the reactor,
+        // as the same descriptor can't be registered twice.
+        // File descriptor becomes readable when process terminates
+        let pidfd = rt::io::Async::new(self.try_clone()?)
+            .map_err(|e| Error::from(e).with_ffi("epoll_ctl"))?;
+        pidfd
+            .readable()
+            .await
+            .map_err(|e| Error::from(e).with_ffi("epoll_wait"))?;
+
+        pidfd.get_ref().reap()
+    }
+
+    fn try_clone(&self) -> ProcessResult<PidFd> {
+        let result = unsafe { libc::fcntl(self.fd, libc::F_DUPFD_CLOEXEC, 0) };
+        if result >= 0 {
+            Ok(PidFd {
+                pid: self.pid,
+                fd: result,
+            })
+        } else {
+            Err(Error::last_os_error().with_ffi("fcntl").into())
+        }
+    }
+
+    /// Reaps the terminated process without blocking.
+    fn reap(&self) -> ProcessResult<Option<ExitStatus>> {
+        let mut info: libc::siginfo_t = unsafe { mem::zeroed() };
+        let result = unsafe {
+            libc::waitid(
+                libc::P_PIDFD,
+                self.fd as libc::id_t,
+                &mut info,
+                libc::WEXITED | libc::WNOHANG,
+            )
+        };
+        if result == 0 {
+            // Zero pid means that there is no terminated child to reap,
+            // which should not happen after the readiness notification
+            return match unsafe { info.si_pid() } {
+                0 => Ok(None),
+                _ => Ok(Some(exit_status(&info))),
+            };
+        }
+
+        let e = io::Error::last_os_error();
+        match e.raw_os_error() {
+            // Not a child of the current process or it was reaped already
+            Some(libc::ECHILD) => Ok(None),
+            // `P_PIDFD` is supported since Linux 5.4 only,
+            // but the terminated child pid can't be reused until it is reaped
+            Some(libc::EINVAL) => {
+                let mut status = 0;
+                match unsafe { libc::waitpid(self.pid, &mut status, libc::WNOHANG) } {
+                    pid if pid == self.pid => Ok(Some(ExitStatus::from_raw(status))),
+                    _ => Ok(None),
+                }
+            }
+            _ => Err(Error::from(e).with_ffi("waitid").into()),
+        }
+    }
+}
+
+impl AsRawFd for PidFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+impl Drop for PidFd {
+    fn drop(&mut self) {
+        let _ = unsafe { libc::close(self.fd) };
+    }
+}
+
+/// Converts `waitid` result into the `waitpid`-like status.
+fn exit_status(info: &libc::siginfo_t) -> ExitStatus {
+    let status = unsafe { info.si_status() };
+    let raw = match info.si_code {
+        libc::CLD_EXITED => (status & 0xff) << 8,
+        libc::CLD_DUMPED => status | 0x80,
+        _ => status,
+    };
+
+    ExitStatus::from_raw(raw)
+}
@@ -0,0 +1,179 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use heim_common::prelude::*;
+use heim_common::sys::linux::parse_cgroup_line;
+use heim_common::units::{information, time, Information, Time};
+use heim_common::utils::iter::{ParseIterator, TryIterator};
+use heim_runtime as rt;
+
+use crate::os::linux::{Cgroup, CgroupStats};
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
+use crate::{Pid, ProcessResult};
+
+/// Finds the hierarchy mount point under the `/sys/fs/cgroup` directory.
+fn mount_point(root: &Path, hierarchy_id: u32, controllers: &[String]) -> PathBuf {
+    if hierarchy_id == 0 && controllers.is_empty() {
+        // Pure cgroups v2 setup has the unified hierarchy mounted right at the root,
+        // while hybrid one (systemd) mounts it into the `unified` sub-directory
+        if root.join("cgroup.controllers").exists() {
+            root.to_path_buf()
+        } else {
+            root.join("unified")
+        }
+    } else {
+        // cgroups v1 hierarchies are usually mounted at the directories
+        // named after the controllers list, ex. `cpu,cpuacct` or `systemd` for `name=systemd`
+        let name = controllers
+            .iter()
+            .map(|controller| controller.trim_start_matches("name="))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        root.join(name)
+    }
+}
+
+fn directory(
+    root: &Path,
+    hierarchy_id: u32,
+    controllers: &[String],
+    path: &Path,
+) -> Option<PathBuf> {
+    let mount_point = mount_point(root, hierarchy_id, controllers);
+    // `Path::join` replaces the whole path if the argument is an absolute one
+    let relative = path.strip_prefix("/").unwrap_or(path);
+    let directory = if relative.as_os_str().is_empty() {
+        mount_point
+    } else {
+        mount_point.join(relative)
+    };
+
+    if directory.is_dir() {
+        Some(directory)
+    } else {
+        None
+    }
+}
+
+pub async fn cgroups(pid: Pid) -> ProcessResult<Vec<Cgroup>> {
+    let contents = rt::fs::read_to_string(process_file_path(pid, "cgroup"))
+        .await
+        .map_err(|e| map_error(pid, e))?;
+
+    rt::spawn_blocking(move || {
+        let root = rt::linux::sysfs_root().join("fs/cgroup");
+        let mut cgroups = Vec::new();
+        for line in contents.lines() {
+            let (hierarchy_id, controllers, path) = parse_cgroup_line(line)?;
+            let directory = directory(&root, hierarchy_id, &controllers, &path);
+
+            cgroups.push(Cgroup {
+                hierarchy_id,
+                controllers,
+                path,
+                directory,
+            });
+        }
+
+        Ok(cgroups)
+    })
+    .await
+}
+
+fn read_optional(path: PathBuf) -> Result<Option<String>> {
+    match fs::read_to_string(&path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(Error::from(e).with_file(path)),
+    }
+}
+
+fn parse_cpu_stat(contents: &str, stats: &mut CgroupStats) -> Result<()> {
+    for line in contents.lines() {
+        let mut parts = line.split_ascii_whitespace();
+        let field = match parts.try_next()? {
+            "usage_usec" => &mut stats.cpu_usage,
+            "user_usec" => &mut stats.cpu_user,
+            "system_usec" => &mut stats.cpu_system,
+            _ => continue,
+        };
+        let value: u64 = parts.try_parse_next()?;
+
+        // TODO: Possible precision loss during the `as f64` cast
+        *field = Some(Time::new::<time::microsecond>(value as f64));
+    }
+
+    Ok(())
+}
+
+fn parse_bytes(contents: &str) -> Result<Information> {
+    let value = contents.trim().parse()?;
+
+    Ok(Information::new::<information::byte>(value))
+}
+
+/// Parses the limit value, where `max` stands for no limit set.
+fn parse_bytes_limit(contents: &str) -> Result<Option<Information>> {
+    match contents.trim() {
+        "max" => Ok(None),
+        value => parse_bytes(value).map(Some),
+    }
+}
+
+pub async fn cgroup_stats(directory: PathBuf) -> Result<CgroupStats> {
+    rt::spawn_blocking(move || {
+        let mut stats = CgroupStats::default();
+
+        if let Some(contents) = read_optional(directory.join("cpu.stat"))? {
+            parse_cpu_stat(&contents, &mut stats)?;
+        }
+        if let Some(contents) = read_optional(directory.join("memory.current"))? {
+            stats.memory_current = Some(parse_bytes(&contents)?);
+        }
+        if let Some(contents) = read_optional(directory.join("memory.max"))? {
+            stats.memory_max = Some(parse_bytes_limit(&contents)?);
+        }
+        if let Some(contents) = read_optional(directory.join("pids.current"))? {
+            stats.pids_current = Some(contents.trim().parse()?);
+        }
+
+        Ok(stats)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::{information, time};
+
+    use super::{parse_bytes, parse_bytes_limit, parse_cpu_stat, CgroupStats};
+
+    #[test]
+    fn test_parse_stats() {
+        let mut stats = CgroupStats::default();
+        parse_cpu_stat(
+            "usage_usec 1500000\nuser_usec 1000000\nsystem_usec 500000\nnr_periods 0\n",
+            &mut stats,
+        )
+        .unwrap();
+
+        assert_eq!(
+            stats.cpu_usage().unwrap().get::<time::millisecond>(),
+            1500.0
+        );
+        assert_eq!(
+            stats.cpu_system().unwrap().get::<time::millisecond>(),
+            500.0
+        );
+        assert_eq!(parse_bytes_limit("max\n").unwrap(), None);
+        assert_eq!(
+            parse_bytes_limit("4096\n")
+                .unwrap()
+                .map(|value| value.get::<information::byte>()),
+            Some(4096)
+        );
+        assert!(parse_bytes("max\n").is_err());
+    }
+}
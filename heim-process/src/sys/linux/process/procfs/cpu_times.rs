@@ -2,7 +2,6 @@ use heim_common::units::Time;
 
 use super::Stat;
 
-#[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct CpuTime {
     utime: Time,
@@ -19,6 +18,14 @@ impl CpuTime {
     pub fn system(&self) -> Time {
         self.stime
     }
+
+    pub fn children_user(&self) -> Time {
+        self.children_utime
+    }
+
+    pub fn children_system(&self) -> Time {
+        self.children_stime
+    }
 }
 
 impl From<Stat> for CpuTime {
@@ -1,10 +1,8 @@
-use std::io;
-
 use heim_runtime as rt;
 
-use crate::{Pid, ProcessError, ProcessResult};
+use crate::{Pid, ProcessResult};
 
-use crate::sys::linux::process::procfs::process_file_path;
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
 pub use crate::sys::unix::{Environment, IntoEnvironmentIter};
 
 pub async fn environment(pid: Pid) -> ProcessResult<Environment> {
@@ -12,10 +10,6 @@ pub async fn environment(pid: Pid) -> ProcessResult<Environment> {
 
     match rt::fs::read(path).await {
         Ok(contents) => Ok(Environment::from_bytes(&contents)),
-        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ProcessError::NoSuchProcess(pid)),
-        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
-            Err(ProcessError::AccessDenied(pid))
-        }
-        Err(e) => Err(e.into()),
+        Err(e) => Err(map_error(pid, e)),
     }
 }
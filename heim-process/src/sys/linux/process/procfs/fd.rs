@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::os::unix::fs::{FileTypeExt, MetadataExt};
+use std::os::unix::io::RawFd;
+use std::path::Path;
+use std::str::FromStr;
+
+use heim_common::prelude::*;
+use heim_common::utils::iter::TryIterator;
+use heim_runtime as rt;
+
+use crate::os::linux::{FileKind, OpenFile};
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Parsed `/proc/<pid>/fdinfo/<fd>` file.
+#[derive(Debug)]
+struct FdInfo {
+    pos: u64,
+    flags: libc::c_int,
+}
+
+impl FromStr for FdInfo {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut pos = None;
+        let mut flags = None;
+        for line in s.lines() {
+            let mut parts = line.split_ascii_whitespace();
+            match parts.try_next()? {
+                "pos:" => pos = Some(parts.try_next()?.parse::<u64>()?),
+                // Flags are represented as an octal number
+                "flags:" => {
+                    let value = parts.try_next()?;
+                    let value = libc::c_int::from_str_radix(value, 8)?;
+                    flags = Some(value)
+                }
+                _ => continue,
+            }
+        }
+
+        match (pos, flags) {
+            (Some(pos), Some(flags)) => Ok(FdInfo { pos, flags }),
+            (None, _) => Err(Error::missing_key("pos", "fdinfo")),
+            (_, None) => Err(Error::missing_key("flags", "fdinfo")),
+        }
+    }
+}
+
+/// Parses the inode number from the `type:[inode]` symlink targets,
+/// as in `socket:[12345]`.
+pub fn parse_inode(value: &str) -> Option<u64> {
+    value
+        .strip_prefix('[')
+        .and_then(|value| value.strip_suffix(']'))
+        .and_then(|value| value.parse().ok())
+}
+
+impl FileKind {
+    /// Classifies the file descriptor by the pseudo-path symlink target
+    /// used by kernel for objects without an actual path,
+    /// see `man 5 proc`, `/proc/[pid]/fd/` section.
+    fn from_link(target: &Path) -> Option<FileKind> {
+        let target = target.to_str()?;
+
+        if let Some(inode) = target.strip_prefix("socket:").and_then(parse_inode) {
+            Some(FileKind::Socket { inode })
+        } else if let Some(inode) = target.strip_prefix("pipe:").and_then(parse_inode) {
+            Some(FileKind::Pipe { inode })
+        } else if let Some(name) = target.strip_prefix("anon_inode:") {
+            // Both `anon_inode:[eventfd]` and `anon_inode:inotify` forms are used
+            let name = name.trim_start_matches('[').trim_end_matches(']');
+
+            Some(FileKind::AnonInode {
+                name: name.to_string(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+fn open_file(pid: Pid, fd: RawFd, path: &Path) -> Result<OpenFile> {
+    let target = fs::read_link(path)?;
+    if let Some(kind) = FileKind::from_link(&target) {
+        return Ok(OpenFile { fd, kind });
+    }
+
+    // `metadata` follows the symlink, so we are getting the target file type here
+    let metadata = fs::metadata(path)?;
+    let file_type = metadata.file_type();
+    let kind = if file_type.is_file() {
+        let info_path = process_file_path(pid, format!("fdinfo/{}", fd));
+        let info = fs::read_to_string(&info_path)?;
+        let FdInfo { pos, flags } = FdInfo::from_str(&info).map_err(|e| e.with_file(info_path))?;
+
+        FileKind::File {
+            path: target,
+            offset: pos,
+            flags,
+        }
+    } else if file_type.is_fifo() {
+        // Named FIFO has an actual path as the symlink target
+        FileKind::Pipe {
+            inode: metadata.ino(),
+        }
+    } else if file_type.is_char_device() || file_type.is_block_device() {
+        FileKind::Device { path: target }
+    } else {
+        FileKind::Other { path: target }
+    };
+
+    Ok(OpenFile { fd, kind })
+}
+
+fn fd_dir(pid: Pid) -> ProcessResult<fs::ReadDir> {
+    match fs::read_dir(process_file_path(pid, "fd")) {
+        Ok(entries) => Ok(entries),
+        Err(e) => Err(map_error(pid, e)),
+    }
+}
+
+fn parse_fd(path: &Path) -> Option<RawFd> {
+    path.file_name()?.to_str()?.parse().ok()
+}
+
+pub async fn open_files(pid: Pid) -> ProcessResult<Vec<OpenFile>> {
+    rt::spawn_blocking(move || {
+        let mut files = Vec::new();
+        for entry in fd_dir(pid)? {
+            let path = entry?.path();
+            let fd = match parse_fd(&path) {
+                Some(fd) => fd,
+                None => continue,
+            };
+
+            match open_file(pid, fd, &path) {
+                Ok(file) => files.push(file),
+                // File descriptor was closed after the `fd/` directory listing
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+                    return Err(ProcessError::AccessDenied(pid))
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(files)
+    })
+    .await
+}
+
+/// Returns file descriptors of the process sockets, grouped by the socket inode number.
+pub fn socket_fds(pid: Pid) -> ProcessResult<HashMap<u64, Vec<RawFd>>> {
+    let mut sockets: HashMap<u64, Vec<RawFd>> = HashMap::new();
+    for entry in fd_dir(pid)? {
+        let path = entry?.path();
+        let fd = match parse_fd(&path) {
+            Some(fd) => fd,
+            None => continue,
+        };
+
+        let target = match fs::read_link(&path) {
+            Ok(target) => target,
+            // File descriptor was closed after the `fd/` directory listing
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+                return Err(ProcessError::AccessDenied(pid))
+            }
+            Err(e) => return Err(e.into()),
+        };
+        if let Some(FileKind::Socket { inode }) = FileKind::from_link(&target) {
+            sockets.entry(inode).or_default().push(fd);
+        }
+    }
+
+    Ok(sockets)
+}
+
+pub async fn num_fds(pid: Pid) -> ProcessResult<usize> {
+    rt::spawn_blocking(move || Ok(fd_dir(pid)?.count())).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use super::{FdInfo, FileKind};
+
+    #[test]
+    fn test_parse_fdinfo() {
+        let info = "pos:\t42\nflags:\t0100002\nmnt_id:\t25\nino:\t3\n";
+        let info = FdInfo::from_str(info).unwrap();
+
+        assert_eq!(info.pos, 42);
+        assert_eq!(info.flags, 0o100002);
+    }
+
+    #[test]
+    fn test_fdinfo_missing_flags() {
+        assert!(FdInfo::from_str("pos:\t0\n").is_err());
+    }
+
+    #[test]
+    fn test_file_kind_from_link() {
+        assert_eq!(
+            FileKind::from_link(Path::new("socket:[31337]")),
+            Some(FileKind::Socket { inode: 31337 })
+        );
+        assert_eq!(
+            FileKind::from_link(Path::new("pipe:[42]")),
+            Some(FileKind::Pipe { inode: 42 })
+        );
+        assert_eq!(
+            FileKind::from_link(Path::new("anon_inode:[eventfd]")),
+            Some(FileKind::AnonInode {
+                name: "eventfd".to_string()
+            })
+        );
+        assert_eq!(
+            FileKind::from_link(Path::new("anon_inode:inotify")),
+            Some(FileKind::AnonInode {
+                name: "inotify".to_string()
+            })
+        );
+        assert_eq!(FileKind::from_link(Path::new("/dev/null")), None);
+    }
+}
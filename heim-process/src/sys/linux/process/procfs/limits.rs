@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use heim_common::prelude::*;
+use heim_common::utils::iter::TryIterator;
+use heim_runtime as rt;
+
+use crate::os::unix::{Limit, Limits, Resource};
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
+use crate::{Pid, ProcessResult};
+
+/// Descriptions used in the `/proc/<pid>/limits` file for each resource.
+const NAMES: [(&str, Resource); 16] = [
+    ("Max cpu time", Resource::Cpu),
+    ("Max file size", Resource::FileSize),
+    ("Max data size", Resource::Data),
+    ("Max stack size", Resource::Stack),
+    ("Max core file size", Resource::Core),
+    ("Max resident set", Resource::Rss),
+    ("Max processes", Resource::NumProcesses),
+    ("Max open files", Resource::NumFiles),
+    ("Max locked memory", Resource::MemoryLock),
+    ("Max address space", Resource::AddressSpace),
+    ("Max file locks", Resource::Locks),
+    ("Max pending signals", Resource::SignalsPending),
+    ("Max msgqueue size", Resource::MessageQueue),
+    ("Max nice priority", Resource::Nice),
+    ("Max realtime priority", Resource::RealtimePriority),
+    ("Max realtime timeout", Resource::RealtimeTimeout),
+];
+
+fn parse_value(value: &str) -> Result<Option<u64>> {
+    match value {
+        "unlimited" => Ok(None),
+        value => Ok(Some(value.parse()?)),
+    }
+}
+
+impl FromStr for Limits {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut limits = Vec::with_capacity(NAMES.len());
+
+        // First line is a table header and the resource description might contain spaces,
+        // so lines are matched against the known descriptions.
+        // Unknown resources are ignored.
+        for line in s.lines().skip(1) {
+            let (name, resource) = match NAMES.iter().find(|(name, _)| line.starts_with(name)) {
+                Some(known) => known,
+                None => continue,
+            };
+            let mut parts = line[name.len()..].split_whitespace();
+            let soft = parse_value(parts.try_next()?)?;
+            let hard = parse_value(parts.try_next()?)?;
+
+            limits.push((*resource, Limit { soft, hard }));
+        }
+
+        Ok(Limits(limits))
+    }
+}
+
+pub async fn limits(pid: Pid) -> ProcessResult<Limits> {
+    let path = process_file_path(pid, "limits");
+    match rt::fs::read_to_string(path.clone()).await {
+        Ok(contents) => Limits::from_str(&contents).map_err(|e| e.with_file(path).into()),
+        Err(e) => Err(map_error(pid, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Limit, Limits, Resource};
+
+    static LIMITS: &str =
+        "Limit                     Soft Limit           Hard Limit           Units
+Max cpu time              unlimited            unlimited            seconds
+Max file size             unlimited            unlimited            bytes
+Max core file size        0                    unlimited            bytes
+Max processes             23961                23961                processes
+Max open files            1024                 524288               files
+Max nice priority         0                    0
+Max future limit          1                    1
+";
+
+    #[test]
+    fn test_parse_limits() {
+        let limits: Limits = LIMITS.parse().unwrap();
+
+        assert_eq!(limits.iter().count(), 6);
+        assert_eq!(
+            limits.get(Resource::NumFiles),
+            Some(Limit::new(Some(1024), Some(524_288)))
+        );
+        assert_eq!(limits.get(Resource::Core), Some(Limit::new(Some(0), None)));
+        assert_eq!(limits.get(Resource::Cpu), Some(Limit::unlimited()));
+        assert_eq!(
+            limits.get(Resource::Nice),
+            Some(Limit::new(Some(0), Some(0)))
+        );
+        assert_eq!(limits.get(Resource::Stack), None);
+    }
+}
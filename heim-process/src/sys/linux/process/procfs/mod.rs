@@ -1,15 +1,56 @@
+use std::io::{Error as IoError, ErrorKind};
+
+use crate::{Pid, ProcessError};
+
+mod cgroup;
 mod command;
 mod cpu_times;
 mod env;
+mod fd;
 mod io;
+mod limits;
+mod net;
+mod ns;
+mod oom;
 mod paths;
+mod schedstat;
+mod smaps;
+mod stack;
 mod stat;
 mod statm;
+mod status;
+mod syscall;
+mod task;
+mod wchan;
 
+pub use self::cgroup::{cgroup_stats, cgroups};
 pub use self::command::{command, Command, CommandIter};
 pub use self::cpu_times::CpuTime;
 pub use self::env::{environment, Environment, IntoEnvironmentIter};
+pub use self::fd::{num_fds, open_files, socket_fds};
 pub use self::io::{io, IoCounters};
+pub use self::limits::limits;
+pub use self::net::connections;
+pub use self::ns::namespaces;
+pub use self::oom::{oom_score, oom_score_adj, set_oom_score_adj};
 pub use self::paths::process_file_path;
-pub use self::stat::{stat, Stat};
+pub use self::schedstat::schedstat;
+pub use self::smaps::{memory_full_info, memory_maps};
+pub use self::stack::stack;
+pub use self::stat::{stat, task_stat, Stat};
 pub use self::statm::{stat_memory, Memory};
+pub use self::status::{status, StatusFile};
+pub use self::syscall::syscall;
+pub use self::task::{children, tids};
+pub use self::wchan::wchan;
+
+/// Maps the error of the `/proc/<pid>/` entry access into the process error.
+pub fn map_error(pid: Pid, e: IoError) -> ProcessError {
+    match e.kind() {
+        ErrorKind::NotFound => ProcessError::NoSuchProcess(pid),
+        ErrorKind::PermissionDenied => ProcessError::AccessDenied(pid),
+        // Process was terminated while its file was being accessed
+        _ if e.raw_os_error() == Some(libc::ESRCH) => ProcessError::NoSuchProcess(pid),
+        _ => e.into(),
+    }
+}
@@ -0,0 +1,374 @@
+use std::fs;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::os::unix::io::RawFd;
+use std::path::PathBuf;
+
+use heim_common::prelude::*;
+use heim_common::utils::iter::TryIterator;
+use heim_runtime as rt;
+
+use crate::os::linux::{AddressFamily, Connection, ConnectionKind, SocketType, TcpState};
+use crate::sys::linux::process::procfs::{process_file_path, socket_fds};
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Sockets table from the `/proc/<pid>/net/` directory.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Table {
+    Tcp,
+    Tcp6,
+    Udp,
+    Udp6,
+    Unix,
+}
+
+impl Table {
+    fn file_name(self) -> &'static str {
+        match self {
+            Table::Tcp => "net/tcp",
+            Table::Tcp6 => "net/tcp6",
+            Table::Udp => "net/udp",
+            Table::Udp6 => "net/udp6",
+            Table::Unix => "net/unix",
+        }
+    }
+
+    fn family(self) -> AddressFamily {
+        match self {
+            Table::Tcp | Table::Udp => AddressFamily::Inet,
+            Table::Tcp6 | Table::Udp6 => AddressFamily::Inet6,
+            Table::Unix => AddressFamily::Unix,
+        }
+    }
+
+    fn parse(self, contents: &str) -> Result<Vec<Socket>> {
+        // First line is a header
+        let lines = contents.lines().skip(1);
+        match self {
+            Table::Unix => lines
+                .filter_map(|line| parse_unix(line).transpose())
+                .collect(),
+            _ => lines.map(|line| parse_inet(self, line)).collect(),
+        }
+    }
+}
+
+impl ConnectionKind {
+    fn tables(self) -> &'static [Table] {
+        match self {
+            ConnectionKind::All => &[
+                Table::Tcp,
+                Table::Tcp6,
+                Table::Udp,
+                Table::Udp6,
+                Table::Unix,
+            ],
+            ConnectionKind::Inet => &[Table::Tcp, Table::Tcp6, Table::Udp, Table::Udp6],
+            ConnectionKind::Inet4 => &[Table::Tcp, Table::Udp],
+            ConnectionKind::Inet6 => &[Table::Tcp6, Table::Udp6],
+            ConnectionKind::Tcp => &[Table::Tcp, Table::Tcp6],
+            ConnectionKind::Tcp4 => &[Table::Tcp],
+            ConnectionKind::Tcp6 => &[Table::Tcp6],
+            ConnectionKind::Udp => &[Table::Udp, Table::Udp6],
+            ConnectionKind::Udp4 => &[Table::Udp],
+            ConnectionKind::Udp6 => &[Table::Udp6],
+            ConnectionKind::Unix => &[Table::Unix],
+        }
+    }
+}
+
+impl TcpState {
+    /// Converts the state value from the `st` column, see `include/net/tcp_states.h`.
+    fn from_code(code: u8) -> Option<TcpState> {
+        let state = match code {
+            0x01 => TcpState::Established,
+            0x02 => TcpState::SynSent,
+            0x03 => TcpState::SynRecv,
+            0x04 => TcpState::FinWait1,
+            0x05 => TcpState::FinWait2,
+            0x06 => TcpState::TimeWait,
+            0x07 => TcpState::Close,
+            0x08 => TcpState::CloseWait,
+            0x09 => TcpState::LastAck,
+            0x0A => TcpState::Listen,
+            0x0B => TcpState::Closing,
+            0x0C => TcpState::NewSynRecv,
+            _ => return None,
+        };
+
+        Some(state)
+    }
+}
+
+/// Sockets table entry.
+#[derive(Debug, Clone)]
+struct Socket {
+    inode: u64,
+    family: AddressFamily,
+    socket_type: SocketType,
+    local_address: Option<SocketAddr>,
+    remote_address: Option<SocketAddr>,
+    path: Option<PathBuf>,
+    state: Option<TcpState>,
+}
+
+impl Socket {
+    fn into_connection(self, fd: RawFd) -> Connection {
+        Connection {
+            fd,
+            inode: self.inode,
+            family: self.family,
+            socket_type: self.socket_type,
+            local_address: self.local_address,
+            remote_address: self.remote_address,
+            path: self.path,
+            state: self.state,
+        }
+    }
+}
+
+/// Parses the `ADDRESS:PORT` pair, where both parts are hex-encoded.
+///
+/// Address is printed by kernel as a sequence of 32-bit words in the host byte order,
+/// while the port is already converted from the network byte order.
+fn parse_address(value: &str) -> Result<SocketAddr> {
+    let mut parts = value.split(':');
+    let address = parts.try_next()?;
+    let port = u16::from_str_radix(parts.try_next()?, 16)?;
+
+    let address = match address.len() {
+        8 => {
+            let word = u32::from_str_radix(address, 16)?;
+            IpAddr::V4(Ipv4Addr::from(word.to_ne_bytes()))
+        }
+        32 => {
+            let mut octets = [0u8; 16];
+            for (idx, chunk) in octets.chunks_exact_mut(4).enumerate() {
+                let word = u32::from_str_radix(&address[idx * 8..idx * 8 + 8], 16)?;
+                chunk.copy_from_slice(&word.to_ne_bytes());
+            }
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        _ => {
+            return Err(Error::from(io::Error::from(io::ErrorKind::InvalidData))
+                .with_message(format!("Invalid socket address: {}", value)))
+        }
+    };
+
+    Ok(SocketAddr::new(address, port))
+}
+
+/// Parses the `/proc/<pid>/net/{tcp,tcp6,udp,udp6}` line:
+///
+/// ```text
+///   sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+///    0: 0100007F:0CEA 00000000:0000 0A 00000000:00000000 00:00000000 00000000   999        0 27458 ...
+/// ```
+fn parse_inet(table: Table, line: &str) -> Result<Socket> {
+    let mut parts = line.split_ascii_whitespace().skip(1);
+    let local_address = parse_address(parts.try_next()?)?;
+    let remote_address = parse_address(parts.try_next()?)?;
+    let state = u8::from_str_radix(parts.try_next()?, 16)?;
+    // Skipping queues, timer, retransmits, uid and timeout columns
+    let inode = parts
+        .nth(5)
+        .ok_or_else(|| Error::missing_key("inode", table.file_name()))?;
+
+    let (socket_type, state) = match table {
+        Table::Tcp | Table::Tcp6 => (SocketType::Stream, TcpState::from_code(state)),
+        // UDP sockets are re-using TCP states, but these values are meaningless for users
+        _ => (SocketType::Datagram, None),
+    };
+    let is_connected = !remote_address.ip().is_unspecified() || remote_address.port() != 0;
+
+    Ok(Socket {
+        inode: inode.parse()?,
+        family: table.family(),
+        socket_type,
+        local_address: Some(local_address),
+        remote_address: if is_connected {
+            Some(remote_address)
+        } else {
+            None
+        },
+        path: None,
+        state,
+    })
+}
+
+/// Parses the `/proc/<pid>/net/unix` line:
+///
+/// ```text
+/// Num       RefCount Protocol Flags    Type St Inode Path
+/// 0000000000000000: 00000002 00000000 00010000 0001 01 27458 /run/systemd/notify
+/// ```
+///
+/// `Ok(None)` is returned for sockets of unknown type.
+fn parse_unix(line: &str) -> Result<Option<Socket>> {
+    let mut parts = line.split_ascii_whitespace().skip(4);
+    let socket_type = match u16::from_str_radix(parts.try_next()?, 16)? as libc::c_int {
+        libc::SOCK_STREAM => SocketType::Stream,
+        libc::SOCK_DGRAM => SocketType::Datagram,
+        libc::SOCK_SEQPACKET => SocketType::SeqPacket,
+        _ => return Ok(None),
+    };
+    let _state = parts.try_next()?;
+    let inode = parts.try_next()?.parse()?;
+    let path = parts.collect::<Vec<_>>().join(" ");
+
+    Ok(Some(Socket {
+        inode,
+        family: AddressFamily::Unix,
+        socket_type,
+        local_address: None,
+        remote_address: None,
+        path: if path.is_empty() {
+            None
+        } else {
+            Some(PathBuf::from(path))
+        },
+        state: None,
+    }))
+}
+
+fn read_table(pid: Pid, table: Table) -> ProcessResult<Vec<Socket>> {
+    let path = process_file_path(pid, table.file_name());
+    match fs::read_to_string(&path) {
+        Ok(contents) => table.parse(&contents).map_err(|e| e.with_file(path).into()),
+        // Table is missing if protocol is not supported by kernel, ex. IPv6 is disabled
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+            Err(ProcessError::AccessDenied(pid))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Joins the sockets opened by the process with the sockets tables from its network namespace.
+pub async fn connections(pid: Pid, kind: ConnectionKind) -> ProcessResult<Vec<Connection>> {
+    rt::spawn_blocking(move || {
+        let fds = socket_fds(pid)?;
+        let mut connections = Vec::new();
+        if fds.is_empty() {
+            return Ok(connections);
+        }
+
+        for table in kind.tables() {
+            for socket in read_table(pid, *table)? {
+                let socket_fds = match fds.get(&socket.inode) {
+                    Some(fds) => fds,
+                    None => continue,
+                };
+                // Same socket might be referred by multiple file descriptors
+                for fd in socket_fds {
+                    connections.push(socket.clone().into_connection(*fd));
+                }
+            }
+        }
+
+        Ok(connections)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use std::path::Path;
+
+    use super::{parse_address, Table};
+    use crate::os::linux::{AddressFamily, SocketType, TcpState};
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_address() {
+        assert_eq!(
+            parse_address("0100007F:1F90").unwrap(),
+            "127.0.0.1:8080".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(
+            parse_address("00000000000000000000000001000000:0016").unwrap(),
+            "[::1]:22".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(
+            parse_address("B80D0120000000000000000001000000:01BB").unwrap(),
+            "[2001:db8::1]:443".parse::<SocketAddr>().unwrap()
+        );
+        assert!(parse_address("0100007F").is_err());
+        assert!(parse_address("0100:1F90").is_err());
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_tcp() {
+        let contents = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 27458 1 0000000000000000 100 0 0 10 0
+   1: 0100007F:B1A6 0100007F:1F90 01 00000000:00000000 00:00000000 00000000  1000        0 31337 1 0000000000000000 20 4 30 10 -1
+";
+        let sockets = Table::Tcp.parse(contents).unwrap();
+        assert_eq!(sockets.len(), 2);
+
+        assert_eq!(sockets[0].inode, 27458);
+        assert_eq!(sockets[0].family, AddressFamily::Inet);
+        assert_eq!(sockets[0].socket_type, SocketType::Stream);
+        assert_eq!(
+            sockets[0].local_address,
+            Some("127.0.0.1:8080".parse().unwrap())
+        );
+        assert_eq!(sockets[0].remote_address, None);
+        assert_eq!(sockets[0].state, Some(TcpState::Listen));
+
+        assert_eq!(sockets[1].inode, 31337);
+        assert_eq!(
+            sockets[1].remote_address,
+            Some("127.0.0.1:8080".parse().unwrap())
+        );
+        assert_eq!(sockets[1].state, Some(TcpState::Established));
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_udp6() {
+        let contents = "  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops
+  210: 00000000000000000000000000000000:14E9 00000000000000000000000000000000:0000 07 00000000:00000000 00:00000000 00000000   104        0 19263 2 0000000000000000 0
+";
+        let sockets = Table::Udp6.parse(contents).unwrap();
+        assert_eq!(sockets.len(), 1);
+        assert_eq!(sockets[0].inode, 19263);
+        assert_eq!(sockets[0].family, AddressFamily::Inet6);
+        assert_eq!(sockets[0].socket_type, SocketType::Datagram);
+        assert_eq!(sockets[0].local_address, Some("[::]:5353".parse().unwrap()));
+        assert_eq!(sockets[0].remote_address, None);
+        assert_eq!(sockets[0].state, None);
+    }
+
+    #[test]
+    fn test_parse_unix() {
+        let contents = "Num       RefCount Protocol Flags    Type St Inode Path
+0000000000000000: 00000002 00000000 00010000 0001 01 27458 /run/systemd/notify
+0000000000000000: 00000003 00000000 00000000 0002 03  1024
+0000000000000000: 00000002 00000000 00010000 0005 01 31337 @/tmp/.X11-unix/X0
+0000000000000000: 00000002 00000000 00000000 0003 01 42 /ignored/raw
+";
+        let sockets = Table::Unix.parse(contents).unwrap();
+        assert_eq!(sockets.len(), 3);
+
+        assert_eq!(sockets[0].inode, 27458);
+        assert_eq!(sockets[0].family, AddressFamily::Unix);
+        assert_eq!(sockets[0].socket_type, SocketType::Stream);
+        assert_eq!(
+            sockets[0].path.as_deref(),
+            Some(Path::new("/run/systemd/notify"))
+        );
+
+        assert_eq!(sockets[1].inode, 1024);
+        assert_eq!(sockets[1].socket_type, SocketType::Datagram);
+        assert_eq!(sockets[1].path, None);
+
+        assert_eq!(sockets[2].socket_type, SocketType::SeqPacket);
+        assert_eq!(
+            sockets[2].path.as_deref(),
+            Some(Path::new("@/tmp/.X11-unix/X0"))
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use std::fs;
+
+use heim_runtime as rt;
+
+use super::fd::parse_inode;
+use crate::os::linux::{Namespace, NamespaceKind, Namespaces};
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
+use crate::{Pid, ProcessResult};
+
+impl NamespaceKind {
+    // `*_for_children` entries are intentionally ignored,
+    // as they are not representing the process own namespaces
+    fn from_file_name(name: &str) -> Option<NamespaceKind> {
+        match name {
+            "cgroup" => Some(NamespaceKind::Cgroup),
+            "ipc" => Some(NamespaceKind::Ipc),
+            "mnt" => Some(NamespaceKind::Mount),
+            "net" => Some(NamespaceKind::Net),
+            "pid" => Some(NamespaceKind::Pid),
+            "time" => Some(NamespaceKind::Time),
+            "user" => Some(NamespaceKind::User),
+            "uts" => Some(NamespaceKind::Uts),
+            _ => None,
+        }
+    }
+}
+
+pub async fn namespaces(pid: Pid) -> ProcessResult<Namespaces> {
+    rt::spawn_blocking(move || {
+        let entries = fs::read_dir(process_file_path(pid, "ns")).map_err(|e| map_error(pid, e))?;
+        let mut namespaces = Vec::with_capacity(8);
+        for entry in entries {
+            let entry = entry?;
+            let kind = match entry
+                .file_name()
+                .to_str()
+                .and_then(NamespaceKind::from_file_name)
+            {
+                Some(kind) => kind,
+                None => continue,
+            };
+
+            // Symlink target has the `type:[inode]` format, ex. `net:[4026531840]`
+            let target = fs::read_link(entry.path()).map_err(|e| map_error(pid, e))?;
+            let inode = target
+                .to_str()
+                .and_then(|target| target.find(':').map(|idx| &target[idx + 1..]))
+                .and_then(parse_inode);
+
+            if let Some(inode) = inode {
+                namespaces.push(Namespace { kind, inode });
+            }
+        }
+        namespaces.sort_by_key(|ns| ns.kind);
+
+        Ok(Namespaces(namespaces))
+    })
+    .await
+}
@@ -0,0 +1,41 @@
+use std::str::FromStr;
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
+use crate::{Pid, ProcessResult};
+
+async fn read_value<T>(pid: Pid, filename: &str) -> ProcessResult<T>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    let path = process_file_path(pid, filename);
+    match rt::fs::read_to_string(path.clone()).await {
+        Ok(contents) => contents
+            .trim()
+            .parse::<T>()
+            .map_err(|e| Error::from(e).with_file(path).into()),
+        Err(e) => Err(map_error(pid, e)),
+    }
+}
+
+/// Reads the `/proc/<pid>/oom_score` file.
+pub async fn oom_score(pid: Pid) -> ProcessResult<u32> {
+    read_value(pid, "oom_score").await
+}
+
+/// Reads the `/proc/<pid>/oom_score_adj` file.
+pub async fn oom_score_adj(pid: Pid) -> ProcessResult<i32> {
+    read_value(pid, "oom_score_adj").await
+}
+
+/// Writes the `value` into the `/proc/<pid>/oom_score_adj` file.
+pub async fn set_oom_score_adj(pid: Pid, value: i32) -> ProcessResult<()> {
+    let path = process_file_path(pid, "oom_score_adj");
+    match rt::fs::write(path.clone(), value.to_string()).await {
+        Ok(()) => Ok(()),
+        Err(e) => Err(map_error(pid, e)),
+    }
+}
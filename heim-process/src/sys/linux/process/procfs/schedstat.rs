@@ -0,0 +1,61 @@
+use std::io;
+
+use heim_common::prelude::*;
+use heim_common::units::{time, Time};
+use heim_common::utils::iter::ParseIterator;
+use heim_runtime as rt;
+
+use crate::os::linux::SchedStat;
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
+use crate::{Pid, ProcessError, ProcessResult};
+
+fn parse(contents: &str) -> Result<SchedStat> {
+    let mut parts = contents.split_ascii_whitespace();
+    let run_time: u64 = parts.try_parse_next()?;
+    let wait_time: u64 = parts.try_parse_next()?;
+    let timeslices = parts.try_parse_next()?;
+
+    // TODO: Possible precision loss during the `as f64` cast
+    Ok(SchedStat {
+        run_time: Time::new::<time::nanosecond>(run_time as f64),
+        wait_time: Time::new::<time::nanosecond>(wait_time as f64),
+        timeslices,
+    })
+}
+
+/// Reads the `/proc/<pid>/schedstat` file.
+///
+/// `None` is returned if kernel was built without the `CONFIG_SCHED_INFO` option.
+pub async fn schedstat(pid: Pid) -> ProcessResult<Option<SchedStat>> {
+    let path = process_file_path(pid, "schedstat");
+    match rt::fs::read_to_string(path.clone()).await {
+        Ok(contents) => parse(&contents)
+            .map(Some)
+            .map_err(|e| e.with_file(path).into()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            // Missing file is expected, but not the missing process directory
+            if rt::fs::path_exists(process_file_path(pid, "")).await {
+                Ok(None)
+            } else {
+                Err(ProcessError::NoSuchProcess(pid))
+            }
+        }
+        Err(e) => Err(map_error(pid, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::time;
+
+    use super::parse;
+
+    #[test]
+    fn test_parse() {
+        let stat = parse("2475018000 96015000 2012\n").unwrap();
+
+        assert_eq!(stat.run_time().get::<time::millisecond>(), 2475.018);
+        assert_eq!(stat.wait_time().get::<time::millisecond>(), 96.015);
+        assert_eq!(stat.timeslices(), 2012);
+    }
+}
@@ -0,0 +1,256 @@
+use std::io;
+use std::ops::AddAssign;
+use std::path::PathBuf;
+
+use heim_common::prelude::*;
+use heim_common::units::{information, Information};
+use heim_common::utils::iter::{ParseIterator, TryIterator};
+use heim_runtime as rt;
+
+use crate::os::linux::{FullMemory, MemoryMap};
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
+use crate::{Pid, ProcessResult};
+
+/// Mapping description, which is the first line of each `/proc/<pid>/smaps` entry,
+/// same to the `/proc/<pid>/maps` line.
+#[derive(Debug)]
+struct Header {
+    start: u64,
+    end: u64,
+    permissions: String,
+    offset: u64,
+    device: libc::dev_t,
+    inode: u64,
+    path: Option<PathBuf>,
+}
+
+impl Header {
+    fn parse(line: &str) -> Result<Header> {
+        // Path might contain spaces, so it is not possible to use `split_ascii_whitespace` here
+        let mut parts = line.splitn(6, ' ');
+        let mut address = parts.try_next()?.splitn(2, '-');
+        let start = u64::from_str_radix(address.try_next()?, 16)?;
+        let end = u64::from_str_radix(address.try_next()?, 16)?;
+        let permissions = parts.try_next()?.to_string();
+        let offset = u64::from_str_radix(parts.try_next()?, 16)?;
+        let mut device = parts.try_next()?.splitn(2, ':');
+        let major = u32::from_str_radix(device.try_next()?, 16)?;
+        let minor = u32::from_str_radix(device.try_next()?, 16)?;
+        let inode = parts.try_parse_next()?;
+        let path = match parts.next().map(str::trim) {
+            Some(path) if !path.is_empty() => Some(PathBuf::from(path)),
+            _ => None,
+        };
+
+        Ok(Header {
+            start,
+            end,
+            permissions,
+            offset,
+            device: libc::makedev(major, minor),
+            inode,
+            path,
+        })
+    }
+}
+
+/// Memory counters of the `smaps` entry, in kibibytes.
+#[derive(Debug, Default)]
+struct Counters {
+    size: u64,
+    rss: u64,
+    pss: u64,
+    private_clean: u64,
+    private_dirty: u64,
+    anonymous: u64,
+    swap: u64,
+    swap_pss: u64,
+}
+
+impl Counters {
+    fn parse_line(&mut self, line: &str) -> Result<()> {
+        let mut parts = line.split_ascii_whitespace();
+        let field = match parts.try_next()? {
+            "Size:" => &mut self.size,
+            "Rss:" => &mut self.rss,
+            "Pss:" => &mut self.pss,
+            "Private_Clean:" => &mut self.private_clean,
+            "Private_Dirty:" => &mut self.private_dirty,
+            "Anonymous:" => &mut self.anonymous,
+            "Swap:" => &mut self.swap,
+            "SwapPss:" => &mut self.swap_pss,
+            _ => return Ok(()),
+        };
+
+        *field = parts.try_parse_next()?;
+
+        Ok(())
+    }
+}
+
+impl AddAssign<&Counters> for Counters {
+    fn add_assign(&mut self, rhs: &Counters) {
+        self.size += rhs.size;
+        self.rss += rhs.rss;
+        self.pss += rhs.pss;
+        self.private_clean += rhs.private_clean;
+        self.private_dirty += rhs.private_dirty;
+        self.anonymous += rhs.anonymous;
+        self.swap += rhs.swap;
+        self.swap_pss += rhs.swap_pss;
+    }
+}
+
+// Despite the `kB` suffix, values are expressed in kibibytes
+fn kib(value: u64) -> Information {
+    Information::new::<information::kibibyte>(value)
+}
+
+impl From<Counters> for FullMemory {
+    fn from(counters: Counters) -> FullMemory {
+        FullMemory {
+            rss: kib(counters.rss),
+            pss: kib(counters.pss),
+            uss: kib(counters.private_clean + counters.private_dirty),
+            swap: kib(counters.swap),
+            swap_pss: kib(counters.swap_pss),
+            anonymous: kib(counters.anonymous),
+        }
+    }
+}
+
+impl From<(Header, Counters)> for MemoryMap {
+    fn from((header, counters): (Header, Counters)) -> MemoryMap {
+        MemoryMap {
+            address: header.start..header.end,
+            permissions: header.permissions,
+            offset: header.offset,
+            device: header.device,
+            inode: header.inode,
+            path: header.path,
+            size: kib(counters.size),
+            rss: kib(counters.rss),
+            pss: kib(counters.pss),
+            private_clean: kib(counters.private_clean),
+            private_dirty: kib(counters.private_dirty),
+            swap: kib(counters.swap),
+        }
+    }
+}
+
+/// Parses both `/proc/<pid>/smaps` and `/proc/<pid>/smaps_rollup` files,
+/// as the latter one has the same format, but with one entry only.
+fn parse(contents: &str) -> Result<Vec<(Header, Counters)>> {
+    let mut entries: Vec<(Header, Counters)> = Vec::new();
+    for line in contents.lines() {
+        let is_counter =
+            matches!(line.split_ascii_whitespace().next(), Some(key) if key.ends_with(':'));
+
+        match entries.last_mut() {
+            Some((_, counters)) if is_counter => counters.parse_line(line)?,
+            _ => entries.push((Header::parse(line)?, Counters::default())),
+        }
+    }
+
+    Ok(entries)
+}
+
+async fn read(pid: Pid, filename: &str) -> io::Result<String> {
+    rt::fs::read_to_string(process_file_path(pid, filename)).await
+}
+
+pub async fn memory_maps(pid: Pid) -> ProcessResult<Vec<MemoryMap>> {
+    let contents = read(pid, "smaps").await.map_err(|e| map_error(pid, e))?;
+    let entries = parse(&contents)?;
+
+    Ok(entries.into_iter().map(Into::into).collect())
+}
+
+pub async fn memory_full_info(pid: Pid) -> ProcessResult<FullMemory> {
+    let contents = match read(pid, "smaps_rollup").await {
+        Ok(contents) => contents,
+        // `smaps_rollup` is available since Linux 4.14 only,
+        // falling back to the summing up all the `smaps` entries
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            read(pid, "smaps").await.map_err(|e| map_error(pid, e))?
+        }
+        Err(e) => return Err(map_error(pid, e)),
+    };
+
+    let total = parse(&contents)?
+        .iter()
+        .fold(Counters::default(), |mut acc, (_, counters)| {
+            acc += counters;
+            acc
+        });
+
+    Ok(total.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use heim_common::units::information;
+
+    use super::{parse, Counters, FullMemory, MemoryMap};
+
+    static SMAPS: &str = "\
+5600a51e8000-5600a51ea000 r--p 00000000 fe:00 317783                     /usr/bin/head
+Size:                  8 kB
+Rss:                   8 kB
+Pss:                   4 kB
+Shared_Clean:          4 kB
+Private_Clean:         4 kB
+Private_Dirty:         0 kB
+Anonymous:             0 kB
+Swap:                  0 kB
+SwapPss:               0 kB
+THPeligible:           0
+VmFlags: rd mr mw me
+7ffd0c5d1000-7ffd0c5f2000 rw-p 00000000 00:00 0
+Size:                132 kB
+Rss:                  12 kB
+Pss:                  12 kB
+Private_Clean:         0 kB
+Private_Dirty:        12 kB
+Anonymous:            12 kB
+Swap:                  4 kB
+SwapPss:               4 kB
+VmFlags: rd wr mr mw me gd ac
+";
+
+    #[test]
+    fn test_parse_smaps() {
+        let entries = parse(SMAPS).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        let maps: Vec<MemoryMap> = entries.into_iter().map(Into::into).collect();
+        assert_eq!(maps[0].address(), 0x5600a51e8000..0x5600a51ea000);
+        assert_eq!(maps[0].permissions(), "r--p");
+        assert_eq!(maps[0].device(), libc::makedev(0xfe, 0));
+        assert_eq!(maps[0].inode(), 317_783);
+        assert_eq!(maps[0].path(), Some(Path::new("/usr/bin/head")));
+        assert_eq!(maps[0].pss().get::<information::kibibyte>(), 4);
+        assert_eq!(maps[1].path(), None);
+        assert_eq!(maps[1].private_dirty().get::<information::kibibyte>(), 12);
+    }
+
+    #[test]
+    fn test_full_memory() {
+        let total =
+            parse(SMAPS)
+                .unwrap()
+                .iter()
+                .fold(Counters::default(), |mut acc, (_, counters)| {
+                    acc += counters;
+                    acc
+                });
+        let memory = FullMemory::from(total);
+
+        assert_eq!(memory.rss().get::<information::kibibyte>(), 20);
+        assert_eq!(memory.uss().get::<information::kibibyte>(), 16);
+        assert_eq!(memory.swap_pss().get::<information::kibibyte>(), 4);
+        assert_eq!(memory.file_backed().get::<information::kibibyte>(), 8);
+    }
+}
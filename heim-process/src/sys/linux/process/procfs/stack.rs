@@ -0,0 +1,77 @@
+use heim_runtime as rt;
+
+use crate::os::linux::StackFrame;
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
+use crate::{Pid, ProcessResult};
+
+fn parse_hex(value: &str) -> Option<u64> {
+    u64::from_str_radix(value.trim_start_matches("0x"), 16).ok()
+}
+
+/// Parses the stack frame line, ex. `[<0>] do_wait+0x1c0/0x270`.
+///
+/// Kernel hides the return address, unless it is read with `CAP_SYSLOG` capability,
+/// and it is not used anyway.
+fn parse_frame(line: &str) -> Option<StackFrame> {
+    let symbol = match line.find("] ") {
+        Some(idx) => &line[idx + 2..],
+        None => line,
+    }
+    .trim();
+    if symbol.is_empty() {
+        return None;
+    }
+
+    let mut parts = symbol.splitn(2, '+');
+    let function = parts.next()?;
+    let (offset, size) = match parts.next() {
+        Some(location) => {
+            let mut parts = location.splitn(2, '/');
+            let offset = parts.next().and_then(parse_hex).unwrap_or(0);
+            let size = parts.next().and_then(parse_hex).unwrap_or(0);
+            (offset, size)
+        }
+        None => (0, 0),
+    };
+
+    Some(StackFrame {
+        function: function.to_string(),
+        offset,
+        size,
+    })
+}
+
+fn parse(contents: &str) -> Vec<StackFrame> {
+    contents.lines().filter_map(parse_frame).collect()
+}
+
+/// Reads the `/proc/<pid>/stack` file.
+///
+/// This file is readable only with `CAP_SYS_ADMIN` capability.
+pub async fn stack(pid: Pid) -> ProcessResult<Vec<StackFrame>> {
+    match rt::fs::read_to_string(process_file_path(pid, "stack")).await {
+        Ok(contents) => Ok(parse(&contents)),
+        Err(e) => Err(map_error(pid, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    #[test]
+    fn test_parse() {
+        let frames = parse(
+            "[<0>] do_wait+0x1c0/0x270
+[<0>] kernel_wait4+0xaa/0x150
+[<0>] entry_SYSCALL_64_after_hwframe+0x76/0x7e
+",
+        );
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].function(), "do_wait");
+        assert_eq!(frames[0].offset(), 0x1c0);
+        assert_eq!(frames[0].size(), 0x270);
+        assert_eq!(frames[2].function(), "entry_SYSCALL_64_after_hwframe");
+    }
+}
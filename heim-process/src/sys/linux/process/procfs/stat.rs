@@ -1,5 +1,7 @@
 use std::io;
+use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Instant;
 
 use heim_common::prelude::*;
 use heim_common::sys::unix::CLOCK_TICKS;
@@ -7,7 +9,8 @@ use heim_common::units::{time, Time};
 use heim_common::utils::iter::{ParseIterator, TryIterator};
 use heim_runtime as rt;
 
-use crate::sys::linux::process::procfs::process_file_path;
+use crate::os::linux::{SchedPolicy, Thread};
+use crate::sys::linux::process::procfs::{process_file_path, CpuTime};
 use crate::{Pid, ProcessError, ProcessResult, Status};
 
 impl Status {
@@ -35,17 +38,45 @@ impl Status {
     }
 }
 
+impl SchedPolicy {
+    pub(crate) fn from_u32(value: u32) -> SchedPolicy {
+        match value {
+            0 => SchedPolicy::Other,
+            1 => SchedPolicy::Fifo,
+            2 => SchedPolicy::RoundRobin,
+            3 => SchedPolicy::Batch,
+            5 => SchedPolicy::Idle,
+            6 => SchedPolicy::Deadline,
+            7 => SchedPolicy::Ext,
+            other => SchedPolicy::Unknown(other),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Stat {
     pub pid: Pid,
     pub name: String,
     pub state: Status,
     pub ppid: Pid,
+    pub pgrp: Pid,
+    pub session_id: Pid,
+    pub tty_nr: i32,
+    pub tpgid: Pid,
+    pub minflt: u64,
+    pub cminflt: u64,
+    pub majflt: u64,
+    pub cmajflt: u64,
+    pub num_threads: u64,
+    pub rt_priority: u32,
+    pub policy: u32,
     pub create_time: Time,
     pub utime: Time,
     pub stime: Time,
     pub cutime: Time,
     pub cstime: Time,
+    /// Aggregated block I/O delays (Linux 2.6.18+).
+    pub blkio_delay: Time,
 }
 
 impl FromStr for Stat {
@@ -69,27 +100,47 @@ impl FromStr for Stat {
             Status::try_from_char(chr)
         })?;
         let ppid: Pid = parts.try_parse_next()?;
-        let _pgrp: i32 = parts.try_parse_next()?;
-        let _session_id: i32 = parts.try_parse_next()?;
-        let _tty_nr: i32 = parts.try_parse_next()?;
-        let _tpgid: i32 = parts.try_parse_next()?;
+        let pgrp: Pid = parts.try_parse_next()?;
+        let session_id: Pid = parts.try_parse_next()?;
+        let tty_nr: i32 = parts.try_parse_next()?;
+        let tpgid: Pid = parts.try_parse_next()?;
         let _flags: u32 = parts.try_parse_next()?;
-        let _minflt: u64 = parts.try_parse_next()?;
-        let _cminflt: u64 = parts.try_parse_next()?;
-        let _majflt: u64 = parts.try_parse_next()?;
-        let _cmajflt: u64 = parts.try_parse_next()?;
+        let minflt: u64 = parts.try_parse_next()?;
+        let cminflt: u64 = parts.try_parse_next()?;
+        let majflt: u64 = parts.try_parse_next()?;
+        let cmajflt: u64 = parts.try_parse_next()?;
         let utime: u64 = parts.try_parse_next()?;
         let stime: u64 = parts.try_parse_next()?;
         let cutime: u64 = parts.try_parse_next()?;
         let cstime: u64 = parts.try_parse_next()?;
         let _priority: i64 = parts.try_parse_next()?;
         let _nice: i64 = parts.try_parse_next()?;
-        let _num_threads: i64 = parts.try_parse_next()?;
+        let num_threads: u64 = parts.try_parse_next()?;
         let _itrealvalue: i64 = parts.try_parse_next()?;
         let start_time: u64 = parts.try_parse_next()?;
         let _vsize: i64 = parts.try_parse_next()?;
         let _rss: i64 = parts.try_parse_next()?;
         let _rsslim: u64 = parts.try_parse_next()?;
+        let _startcode: u64 = parts.try_parse_next()?;
+        let _endcode: u64 = parts.try_parse_next()?;
+        let _startstack: u64 = parts.try_parse_next()?;
+        let _kstkesp: u64 = parts.try_parse_next()?;
+        let _kstkeip: u64 = parts.try_parse_next()?;
+        let _signal: u64 = parts.try_parse_next()?;
+        let _blocked: u64 = parts.try_parse_next()?;
+        let _sigignore: u64 = parts.try_parse_next()?;
+        let _sigcatch: u64 = parts.try_parse_next()?;
+        let _wchan: u64 = parts.try_parse_next()?;
+        let _nswap: u64 = parts.try_parse_next()?;
+        let _cnswap: u64 = parts.try_parse_next()?;
+        let _exit_signal: i32 = parts.try_parse_next()?;
+        let _processor: i32 = parts.try_parse_next()?;
+        let rt_priority: u32 = parts.try_parse_next()?;
+        let policy: u32 = parts.try_parse_next()?;
+        let blkio_ticks: u64 = match parts.next() {
+            Some(value) => value.parse()?,
+            None => 0,
+        };
         // ...
 
         // Note: we need to operate with `f64` in here for as much as possible,
@@ -107,18 +158,43 @@ impl FromStr for Stat {
             name,
             state,
             ppid,
+            pgrp,
+            session_id,
+            tty_nr,
+            tpgid,
+            minflt,
+            cminflt,
+            majflt,
+            cmajflt,
+            num_threads,
+            rt_priority,
+            policy,
             create_time: Time::new::<time::second>(start_time),
             // TODO: Possible precision loss during the `as f64` cast
             utime: Time::new::<time::second>(utime as f64 / ticks),
             stime: Time::new::<time::second>(stime as f64 / ticks),
             cutime: Time::new::<time::second>(cutime as f64 / ticks),
             cstime: Time::new::<time::second>(cstime as f64 / ticks),
+            blkio_delay: Time::new::<time::second>(blkio_ticks as f64 / ticks),
         })
     }
 }
 
 pub async fn stat(pid: Pid) -> ProcessResult<Stat> {
-    let path = process_file_path(pid, "stat");
+    read_stat(pid, process_file_path(pid, "stat")).await
+}
+
+/// Reads the `/proc/<pid>/task/<tid>/stat` file for the `tid` thread of the `pid` process.
+///
+/// Same to `stat`, but `NoSuchProcess` error is returned with the `tid` value,
+/// as the thread might be gone while the process itself is still alive.
+pub async fn task_stat(pid: Pid, tid: Pid) -> ProcessResult<Stat> {
+    let path = process_file_path(pid, format!("task/{}/stat", tid));
+
+    read_stat(tid, path).await
+}
+
+async fn read_stat(pid: Pid, path: PathBuf) -> ProcessResult<Stat> {
     // TODO: Get rid of the `.clone`
     let contents = match rt::fs::read_to_string(path.clone()).await {
         Ok(contents) => contents,
@@ -135,3 +211,53 @@ pub async fn stat(pid: Pid) -> ProcessResult<Stat> {
 
     Ok(stats)
 }
+
+impl Thread {
+    pub(crate) fn from_stat(stat: Stat) -> Thread {
+        Thread {
+            tid: stat.pid,
+            name: stat.name.clone(),
+            status: stat.state,
+            cpu_time: CpuTime::from(stat).into(),
+            at: Instant::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use heim_common::units::time;
+
+    use super::Stat;
+    use crate::Status;
+
+    #[test]
+    fn test_parse_stat() {
+        let stat = Stat::from_str(
+            "1261 (tmux: server) S 1 1261 1261 0 -1 4194560 1517 274 12 3 \
+             1 2 0 0 20 0 4 0 222644 2703360 335 18446744073709551615 94296713084928 \
+             94296713104809 140721073518016 0 0 0 0 0 0 0 0 0 17 3 10 2 0 0 0 \
+             94296713120816 94296713122432 94296749023232 140721073522074 \
+             140721073522094 140721073522094 140721073524715 0",
+        )
+        .unwrap();
+
+        assert_eq!(stat.name, "tmux: server");
+        assert_eq!(stat.state, Status::Sleeping);
+        assert_eq!(stat.ppid, 1);
+        assert_eq!(stat.pgrp, 1261);
+        assert_eq!(stat.session_id, 1261);
+        assert_eq!(stat.tty_nr, 0);
+        assert_eq!(stat.tpgid, -1);
+        assert_eq!(stat.minflt, 1517);
+        assert_eq!(stat.cminflt, 274);
+        assert_eq!(stat.majflt, 12);
+        assert_eq!(stat.cmajflt, 3);
+        assert_eq!(stat.num_threads, 4);
+        assert_eq!(stat.rt_priority, 10);
+        assert_eq!(stat.policy, 2);
+        assert_eq!(stat.blkio_delay.get::<time::second>(), 0.0);
+    }
+}
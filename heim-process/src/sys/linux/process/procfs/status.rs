@@ -0,0 +1,267 @@
+use std::io;
+use std::str::FromStr;
+
+use heim_common::prelude::*;
+use heim_common::utils::iter::ParseIterator;
+use heim_runtime as rt;
+
+use crate::os::linux::Seccomp;
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
+use crate::{Pid, ProcessResult};
+
+/// Parsed `/proc/<pid>/status` file.
+///
+/// Only the fields which are not available in the `/proc/<pid>/stat` file are parsed.
+/// Since the file contents depends on the kernel version,
+/// all the fields are optional and it is up to the caller
+/// to decide what to do with the missing ones.
+#[derive(Debug, Default)]
+pub struct StatusFile {
+    /// Real, effective, saved set and filesystem UIDs.
+    pub uid: Option<[libc::uid_t; 4]>,
+    /// Real, effective, saved set and filesystem GIDs.
+    pub gid: Option<[libc::gid_t; 4]>,
+    /// Supplementary groups.
+    pub groups: Option<Vec<libc::gid_t>>,
+    /// Process ids in each of the nested PID namespaces, outermost first (Linux 4.1+).
+    pub ns_pids: Option<Vec<Pid>>,
+    /// Inheritable capabilities.
+    pub cap_inh: Option<u64>,
+    /// Permitted capabilities.
+    pub cap_prm: Option<u64>,
+    /// Effective capabilities.
+    pub cap_eff: Option<u64>,
+    /// Capabilities bounding set (Linux 2.6.26+).
+    pub cap_bnd: Option<u64>,
+    /// Ambient capabilities (Linux 4.3+).
+    pub cap_amb: Option<u64>,
+    /// Value of the `no_new_privs` bit (Linux 4.10+).
+    pub no_new_privs: Option<bool>,
+    /// Raw seccomp mode (Linux 3.8+).
+    ///
+    /// It is parsed by the [Seccomp::try_from_str] on demand,
+    /// so the unknown modes are not failing the whole file parsing.
+    pub seccomp: Option<String>,
+    /// Speculation flaw mitigation state (Linux 4.17+).
+    pub speculation_store_bypass: Option<String>,
+    /// CPUs on which the process may be scheduled (Linux 2.6.26+).
+    pub cpus_allowed_list: Option<Vec<usize>>,
+    /// Memory nodes allowed to the process (Linux 2.6.26+).
+    pub mems_allowed_list: Option<Vec<usize>>,
+    /// Number of voluntary context switches (Linux 2.6.23+).
+    pub voluntary_ctxt_switches: Option<u64>,
+    /// Number of involuntary context switches (Linux 2.6.23+).
+    pub nonvoluntary_ctxt_switches: Option<u64>,
+}
+
+impl StatusFile {
+    /// Creates an error for the `key` which is missing in the file.
+    pub fn missing_key(key: &'static str) -> Error {
+        Error::missing_key(key, "/proc/<pid>/status")
+    }
+}
+
+impl Seccomp {
+    pub(crate) fn try_from_str(value: &str) -> Result<Seccomp> {
+        match value {
+            "0" => Ok(Seccomp::Disabled),
+            "1" => Ok(Seccomp::Strict),
+            "2" => Ok(Seccomp::Filter),
+            other => {
+                let inner = io::Error::from(io::ErrorKind::InvalidData);
+
+                Err(Error::from(inner).with_message(format!("Unknown seccomp mode {}", other)))
+            }
+        }
+    }
+}
+
+fn parse_ids(value: &str) -> Result<[libc::uid_t; 4]> {
+    let mut parts = value.split_ascii_whitespace();
+
+    Ok([
+        parts.try_parse_next()?,
+        parts.try_parse_next()?,
+        parts.try_parse_next()?,
+        parts.try_parse_next()?,
+    ])
+}
+
+fn parse_list<T: FromStr>(value: &str) -> Result<Vec<T>>
+where
+    Error: From<<T as FromStr>::Err>,
+{
+    value
+        .split_ascii_whitespace()
+        .map(|item| T::from_str(item).map_err(Into::into))
+        .collect()
+}
+
+/// Parses the ranges list, ex. `0-3,8,10-11`.
+fn parse_ranges(value: &str) -> Result<Vec<usize>> {
+    let mut list = Vec::new();
+    for range in value.split(',').filter(|range| !range.is_empty()) {
+        let mut parts = range.splitn(2, '-');
+        let start: usize = parts.try_parse_next()?;
+        let end = match parts.next() {
+            Some(end) => end.parse()?,
+            None => start,
+        };
+        list.extend(start..=end);
+    }
+
+    Ok(list)
+}
+
+impl FromStr for StatusFile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut status = StatusFile::default();
+        for line in s.lines() {
+            let mut parts = line.splitn(2, ':');
+            let (key, value) = match (parts.next(), parts.next()) {
+                (Some(key), Some(value)) => (key, value.trim()),
+                _ => continue,
+            };
+
+            match key {
+                "Uid" => status.uid = Some(parse_ids(value)?),
+                "Gid" => status.gid = Some(parse_ids(value)?),
+                "Groups" => status.groups = Some(parse_list(value)?),
+                "NSpid" => status.ns_pids = Some(parse_list(value)?),
+                "CapInh" => status.cap_inh = Some(u64::from_str_radix(value, 16)?),
+                "CapPrm" => status.cap_prm = Some(u64::from_str_radix(value, 16)?),
+                "CapEff" => status.cap_eff = Some(u64::from_str_radix(value, 16)?),
+                "CapBnd" => status.cap_bnd = Some(u64::from_str_radix(value, 16)?),
+                "CapAmb" => status.cap_amb = Some(u64::from_str_radix(value, 16)?),
+                "NoNewPrivs" => status.no_new_privs = Some(value != "0"),
+                "Seccomp" => status.seccomp = Some(value.to_string()),
+                "Speculation_Store_Bypass" => {
+                    status.speculation_store_bypass = Some(value.to_string())
+                }
+                "Cpus_allowed_list" => status.cpus_allowed_list = Some(parse_ranges(value)?),
+                "Mems_allowed_list" => status.mems_allowed_list = Some(parse_ranges(value)?),
+                "voluntary_ctxt_switches" => status.voluntary_ctxt_switches = Some(value.parse()?),
+                "nonvoluntary_ctxt_switches" => {
+                    status.nonvoluntary_ctxt_switches = Some(value.parse()?)
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(status)
+    }
+}
+
+pub async fn status(pid: Pid) -> ProcessResult<StatusFile> {
+    let path = process_file_path(pid, "status");
+    match rt::fs::read_to_string(path.clone()).await {
+        Ok(contents) => StatusFile::from_str(&contents).map_err(|e| e.with_file(path).into()),
+        Err(e) => Err(map_error(pid, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::StatusFile;
+    use crate::os::linux::Seccomp;
+
+    static STATUS: &str = "\
+Name:\tsshd
+Umask:\t0022
+State:\tS (sleeping)
+Tgid:\t1261
+Pid:\t1261
+PPid:\t1
+Uid:\t0\t1000\t0\t1000
+Gid:\t0\t0\t100\t0
+FDSize:\t64
+Groups:\t4 24 27 \n\
+NStgid:\t1261\t7
+NSpid:\t1261\t7
+VmPeak:\t   15300 kB
+Threads:\t1
+CapInh:\t0000000000000000
+CapPrm:\t000001ffffffffff
+CapEff:\t0000000000200000
+CapBnd:\t000001ffffffffff
+CapAmb:\t0000000000000000
+NoNewPrivs:\t1
+Seccomp:\t2
+Seccomp_filters:\t1
+Speculation_Store_Bypass:\tthread force mitigated
+Cpus_allowed:\tff
+Cpus_allowed_list:\t0-2,5,7-8
+Mems_allowed:\t00000000,00000001
+Mems_allowed_list:\t0
+voluntary_ctxt_switches:\t150
+nonvoluntary_ctxt_switches:\t3
+";
+
+    #[test]
+    fn test_parse_credentials() {
+        let status = StatusFile::from_str(STATUS).unwrap();
+
+        assert_eq!(status.uid, Some([0, 1000, 0, 1000]));
+        assert_eq!(status.gid, Some([0, 0, 100, 0]));
+        assert_eq!(status.groups, Some(vec![4, 24, 27]));
+    }
+
+    #[test]
+    fn test_parse_security() {
+        let status = StatusFile::from_str(STATUS).unwrap();
+
+        assert_eq!(status.cap_inh, Some(0));
+        assert_eq!(status.cap_prm, Some(0x1ff_ffff_ffff));
+        assert_eq!(status.cap_eff, Some(1 << 21));
+        assert_eq!(status.no_new_privs, Some(true));
+        assert_eq!(status.seccomp.as_deref(), Some("2"));
+        assert_eq!(
+            status.speculation_store_bypass.as_deref(),
+            Some("thread force mitigated")
+        );
+    }
+
+    #[test]
+    fn test_parse_allowed_lists() {
+        let status = StatusFile::from_str(STATUS).unwrap();
+
+        assert_eq!(status.cpus_allowed_list, Some(vec![0, 1, 2, 5, 7, 8]));
+        assert_eq!(status.mems_allowed_list, Some(vec![0]));
+    }
+
+    #[test]
+    fn test_parse_ctx_switches() {
+        let status = StatusFile::from_str(STATUS).unwrap();
+
+        assert_eq!(status.voluntary_ctxt_switches, Some(150));
+        assert_eq!(status.nonvoluntary_ctxt_switches, Some(3));
+    }
+
+    #[test]
+    fn test_parse_ns_pids() {
+        let status = StatusFile::from_str(STATUS).unwrap();
+
+        assert_eq!(status.ns_pids, Some(vec![1261, 7]));
+    }
+
+    #[test]
+    fn test_parse_unknown_seccomp() {
+        let status = StatusFile::from_str("Uid:\t0\t0\t0\t0\nSeccomp:\t3\n").unwrap();
+
+        assert_eq!(status.uid, Some([0, 0, 0, 0]));
+        assert!(Seccomp::try_from_str(status.seccomp.as_deref().unwrap()).is_err());
+        assert_eq!(Seccomp::try_from_str("2").unwrap(), Seccomp::Filter);
+    }
+
+    #[test]
+    fn test_parse_empty_groups() {
+        let status = StatusFile::from_str("Groups:\t \n").unwrap();
+
+        assert_eq!(status.groups, Some(vec![]));
+        assert_eq!(status.uid, None);
+    }
+}
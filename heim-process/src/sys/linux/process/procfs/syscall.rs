@@ -0,0 +1,76 @@
+use heim_common::prelude::*;
+use heim_common::utils::iter::{ParseIterator, TryIterator};
+use heim_runtime as rt;
+
+use crate::os::linux::Syscall;
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
+use crate::{Pid, ProcessResult};
+
+fn parse_hex(value: &str) -> Result<u64> {
+    u64::from_str_radix(value.trim_start_matches("0x"), 16).map_err(Into::into)
+}
+
+/// Parses the `/proc/<pid>/syscall` file contents.
+///
+/// File contains either a `running` word, if process is not blocked,
+/// or `-1` followed by the stack pointer and program counter if process is blocked not in a system call,
+/// or system call number followed by six arguments, stack pointer and program counter.
+fn parse(contents: &str) -> Result<Option<Syscall>> {
+    let contents = contents.trim();
+    if contents == "running" {
+        return Ok(None);
+    }
+
+    let mut parts = contents.split_ascii_whitespace();
+    let number: libc::c_long = parts.try_parse_next()?;
+    if number < 0 {
+        return Ok(None);
+    }
+
+    let mut args = [0; 6];
+    for arg in args.iter_mut() {
+        *arg = parse_hex(parts.try_next()?)?;
+    }
+    let stack_pointer = parse_hex(parts.try_next()?)?;
+    let program_counter = parse_hex(parts.try_next()?)?;
+
+    Ok(Some(Syscall {
+        number,
+        args,
+        stack_pointer,
+        program_counter,
+    }))
+}
+
+/// Reads the `/proc/<pid>/syscall` file.
+///
+/// Reading it requires the same permissions as for attaching to the process with `ptrace`.
+pub async fn syscall(pid: Pid) -> ProcessResult<Option<Syscall>> {
+    let path = process_file_path(pid, "syscall");
+    match rt::fs::read_to_string(path.clone()).await {
+        Ok(contents) => parse(&contents).map_err(|e| e.with_file(path).into()),
+        Err(e) => Err(map_error(pid, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("running\n").unwrap(), None);
+        assert_eq!(parse("-1 0x7ffd6c0ba3f8 0x7f3c2d0e9d4a\n").unwrap(), None);
+
+        let syscall =
+            parse("61 0xffffffff 0x7ffd6c0ba47c 0x0 0x0 0x0 0x0 0x7ffd6c0ba458 0x7f3c2d0e9d4a\n")
+                .unwrap()
+                .unwrap();
+        assert_eq!(syscall.number(), 61);
+        assert_eq!(syscall.args(), &[0xffff_ffff, 0x7ffd_6c0b_a47c, 0, 0, 0, 0]);
+        assert_eq!(syscall.stack_pointer(), 0x7ffd_6c0b_a458);
+        assert_eq!(syscall.program_counter(), 0x7f3c_2d0e_9d4a);
+
+        assert!(parse("61 0xffffffff\n").is_err());
+    }
+}
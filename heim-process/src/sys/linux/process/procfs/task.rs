@@ -0,0 +1,63 @@
+use std::fs;
+use std::io;
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
+use crate::{Pid, ProcessError, ProcessResult};
+
+/// Returns a stream over the thread ids of the `pid` process,
+/// taken from the `/proc/<pid>/task` directory.
+pub async fn tids(pid: Pid) -> ProcessResult<impl Stream<Item = ProcessResult<Pid>>> {
+    let entries = match rt::fs::read_dir(process_file_path(pid, "task")).await {
+        Ok(entries) => entries,
+        Err(e) => return Err(map_error(pid, e)),
+    };
+
+    let stream = entries
+        .map_err(Into::into)
+        .try_filter_map(|entry| async move {
+            let res = match entry.file_name().to_str() {
+                Some(name) => name.parse::<Pid>().ok(),
+                None => None,
+            };
+
+            Ok(res)
+        });
+
+    Ok(stream)
+}
+
+/// Returns pids of the `pid` process children,
+/// taken from the `/proc/<pid>/task/<tid>/children` files.
+///
+/// `None` is returned if kernel was built without the `CONFIG_PROC_CHILDREN` option.
+pub async fn children(pid: Pid) -> ProcessResult<Option<Vec<Pid>>> {
+    rt::spawn_blocking(move || {
+        let entries =
+            fs::read_dir(process_file_path(pid, "task")).map_err(|e| map_error(pid, e))?;
+
+        let mut children = Vec::new();
+        for entry in entries {
+            let task = entry?.path();
+            let contents = match fs::read_to_string(task.join("children")) {
+                Ok(contents) => contents,
+                // Thread was terminated after the `task/` directory listing
+                Err(e) if e.kind() == io::ErrorKind::NotFound && !task.exists() => continue,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+                    return Err(ProcessError::AccessDenied(pid))
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            for child in contents.split_ascii_whitespace() {
+                children.push(child.parse::<Pid>().map_err(Error::from)?);
+            }
+        }
+
+        Ok(Some(children))
+    })
+    .await
+}
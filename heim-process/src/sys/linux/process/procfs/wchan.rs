@@ -0,0 +1,31 @@
+use heim_runtime as rt;
+
+use crate::sys::linux::process::procfs::{map_error, process_file_path};
+use crate::{Pid, ProcessResult};
+
+fn parse(contents: &str) -> Option<String> {
+    match contents.trim() {
+        "" | "0" => None,
+        symbol => Some(symbol.to_string()),
+    }
+}
+
+/// Reads the `/proc/<pid>/wchan` file.
+pub async fn wchan(pid: Pid) -> ProcessResult<Option<String>> {
+    match rt::fs::read_to_string(process_file_path(pid, "wchan")).await {
+        Ok(contents) => Ok(parse(&contents)),
+        Err(e) => Err(map_error(pid, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("do_wait"), Some("do_wait".to_string()));
+        assert_eq!(parse("0"), None);
+        assert_eq!(parse(""), None);
+    }
+}
@@ -0,0 +1,90 @@
+use std::fs;
+use std::os::unix::fs::{FileTypeExt, MetadataExt};
+use std::path::{Path, PathBuf};
+
+use heim_runtime as rt;
+
+/// Decodes major and minor device numbers from the `tty_nr` field of the `/proc/<pid>/stat` file.
+///
+/// Minor number is stored in the bits 31 to 20 and 7 to 0, major one is in the bits 15 to 8.
+fn decode(tty_nr: u32) -> (u32, u32) {
+    let major = (tty_nr >> 8) & 0xfff;
+    let minor = (tty_nr & 0xff) | ((tty_nr >> 12) & 0xf_ff00);
+
+    (major, minor)
+}
+
+/// Guesses path for the well-known terminal devices,
+/// see the `Documentation/admin-guide/devices.txt` in the kernel sources.
+fn guess(major: u32, minor: u32) -> Option<PathBuf> {
+    let path = match major {
+        // Unix98 PTY slaves
+        136..=143 => format!("/dev/pts/{}", (major - 136) * 256 + minor),
+        4 if minor < 64 => format!("/dev/tty{}", minor),
+        4 => format!("/dev/ttyS{}", minor - 64),
+        5 if minor == 1 => "/dev/console".to_string(),
+        _ => return None,
+    };
+
+    Some(PathBuf::from(path))
+}
+
+fn is_device(path: &Path, rdev: libc::dev_t) -> bool {
+    match fs::metadata(path) {
+        Ok(metadata) => metadata.file_type().is_char_device() && metadata.rdev() == rdev,
+        Err(..) => false,
+    }
+}
+
+fn find_device(directory: &str, rdev: libc::dev_t) -> Option<PathBuf> {
+    fs::read_dir(directory)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| is_device(path, rdev))
+}
+
+/// Resolves the controlling terminal device path from the `tty_nr` value.
+///
+/// Well-known device path is checked first and if it does not match,
+/// `/dev/pts` and `/dev` directories are scanned for the device with the same number.
+pub async fn terminal(tty_nr: i32) -> Option<PathBuf> {
+    if tty_nr == 0 {
+        return None;
+    }
+
+    rt::spawn_blocking(move || {
+        let (major, minor) = decode(tty_nr as u32);
+        let rdev = libc::makedev(major, minor);
+
+        match guess(major, minor) {
+            Some(path) if is_device(&path, rdev) => Some(path),
+            _ => find_device("/dev/pts", rdev).or_else(|| find_device("/dev", rdev)),
+        }
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{decode, guess};
+
+    #[test]
+    fn test_decode() {
+        // `/dev/pts/3`
+        assert_eq!(decode(34819), (136, 3));
+        // `/dev/pts/300`, which has minor number stored in the upper bits
+        assert_eq!(decode(0x0010_882c), (136, 300));
+    }
+
+    #[test]
+    fn test_guess() {
+        assert_eq!(guess(136, 300), Some(PathBuf::from("/dev/pts/300")));
+        assert_eq!(guess(137, 44), Some(PathBuf::from("/dev/pts/300")));
+        assert_eq!(guess(4, 1), Some(PathBuf::from("/dev/tty1")));
+        assert_eq!(guess(4, 65), Some(PathBuf::from("/dev/ttyS1")));
+        assert_eq!(guess(1, 3), None);
+    }
+}
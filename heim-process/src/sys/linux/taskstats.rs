@@ -0,0 +1,316 @@
+//! Per-task statistics interface over the generic netlink,
+//! see `Documentation/accounting/taskstats.rst` in the kernel sources.
+
+use std::convert::TryInto;
+use std::io;
+use std::os::unix::io::RawFd;
+
+use heim_common::units::{time, Time};
+use heim_runtime as rt;
+
+use crate::os::linux::Taskstats;
+use crate::{Pid, ProcessError, ProcessResult};
+
+const TASKSTATS_GENL_NAME: &[u8] = b"TASKSTATS\0";
+const TASKSTATS_GENL_VERSION: u8 = 1;
+const TASKSTATS_CMD_GET: u8 = 1;
+const TASKSTATS_CMD_ATTR_TGID: u16 = 2;
+const TASKSTATS_TYPE_STATS: u16 = 3;
+const TASKSTATS_TYPE_AGGR_TGID: u16 = 5;
+
+/// Size of the `struct nlmsghdr`.
+const NLMSG_HDRLEN: usize = 16;
+/// Size of the `struct genlmsghdr`.
+const GENL_HDRLEN: usize = 4;
+/// Size of the `struct nlattr` header.
+const NLA_HDRLEN: usize = 4;
+/// Attribute type bits, excluding the `NLA_F_NESTED` and `NLA_F_NET_BYTEORDER` flags.
+const NLA_TYPE_MASK: u16 = 0x3fff;
+
+#[derive(Debug)]
+struct Socket {
+    fd: RawFd,
+    buf: Vec<u8>,
+}
+
+impl Socket {
+    fn open() -> io::Result<Socket> {
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                libc::NETLINK_GENERIC,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Socket {
+            fd,
+            buf: vec![0; 8192],
+        })
+    }
+
+    /// Sends the generic netlink request with a single attribute
+    /// and returns the response attributes.
+    fn request(
+        &mut self,
+        kind: u16,
+        cmd: u8,
+        version: u8,
+        attr: u16,
+        payload: &[u8],
+    ) -> io::Result<&[u8]> {
+        let attr_len = NLA_HDRLEN + payload.len();
+        let len = NLMSG_HDRLEN + GENL_HDRLEN + align(attr_len);
+        let mut buf = Vec::with_capacity(len);
+        // struct nlmsghdr
+        buf.extend_from_slice(&(len as u32).to_ne_bytes());
+        buf.extend_from_slice(&kind.to_ne_bytes());
+        buf.extend_from_slice(&(libc::NLM_F_REQUEST as u16).to_ne_bytes());
+        buf.extend_from_slice(&0u32.to_ne_bytes());
+        buf.extend_from_slice(&0u32.to_ne_bytes());
+        // struct genlmsghdr
+        buf.extend_from_slice(&[cmd, version, 0, 0]);
+        // struct nlattr
+        buf.extend_from_slice(&(attr_len as u16).to_ne_bytes());
+        buf.extend_from_slice(&attr.to_ne_bytes());
+        buf.extend_from_slice(payload);
+        buf.resize(len, 0);
+
+        let result = unsafe { libc::send(self.fd, buf.as_ptr() as *const libc::c_void, len, 0) };
+        if result < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let result = unsafe {
+            libc::recv(
+                self.fd,
+                self.buf.as_mut_ptr() as *mut libc::c_void,
+                self.buf.len(),
+                0,
+            )
+        };
+        if result < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        parse_response(&self.buf[..result as usize], kind)
+    }
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        let _ = unsafe { libc::close(self.fd) };
+    }
+}
+
+/// Attributes are aligned to the 4 bytes boundary.
+fn align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+fn read_u16(buf: &[u8], offset: usize) -> Option<u16> {
+    let bytes = buf.get(offset..offset + 2)?;
+
+    bytes.try_into().ok().map(u16::from_ne_bytes)
+}
+
+fn read_u32(buf: &[u8], offset: usize) -> Option<u32> {
+    let bytes = buf.get(offset..offset + 4)?;
+
+    bytes.try_into().ok().map(u32::from_ne_bytes)
+}
+
+fn read_u64(buf: &[u8], offset: usize) -> Option<u64> {
+    let bytes = buf.get(offset..offset + 8)?;
+
+    bytes.try_into().ok().map(u64::from_ne_bytes)
+}
+
+/// Returns the attributes of the `kind` response message
+/// or the error from the `NLMSG_ERROR` one.
+fn parse_response(buf: &[u8], kind: u16) -> io::Result<&[u8]> {
+    let invalid = || io::Error::from(io::ErrorKind::InvalidData);
+
+    let len = read_u32(buf, 0).ok_or_else(invalid)? as usize;
+    let msg = buf.get(NLMSG_HDRLEN..len).ok_or_else(invalid)?;
+    match read_u16(buf, 4).ok_or_else(invalid)? {
+        value if value == libc::NLMSG_ERROR as u16 => {
+            let error = read_u32(msg, 0).ok_or_else(invalid)? as i32;
+            Err(io::Error::from_raw_os_error(-error))
+        }
+        value if value == kind => msg.get(GENL_HDRLEN..).ok_or_else(invalid),
+        _ => Err(invalid()),
+    }
+}
+
+/// Finds the attribute of the `kind` and returns its payload.
+fn find_attribute(mut buf: &[u8], kind: u16) -> Option<&[u8]> {
+    while let (Some(len), Some(attr)) = (read_u16(buf, 0), read_u16(buf, 2)) {
+        let len = len as usize;
+        if len < NLA_HDRLEN {
+            return None;
+        }
+        let payload = buf.get(NLA_HDRLEN..len)?;
+        if attr & NLA_TYPE_MASK == kind {
+            return Some(payload);
+        }
+
+        buf = buf.get(align(len)..).unwrap_or(&[]);
+    }
+
+    None
+}
+
+/// Parses the `struct taskstats`, field offsets are taken from the `linux/taskstats.h`.
+fn parse_stats(buf: &[u8]) -> Option<Taskstats> {
+    let nanoseconds =
+        |offset| read_u64(buf, offset).map(|value| Time::new::<time::nanosecond>(value as f64));
+    let version = read_u16(buf, 0)?;
+    let (freepages_count, freepages_delay) = if version >= 7 {
+        (read_u64(buf, 312), nanoseconds(320))
+    } else {
+        (None, None)
+    };
+    let (thrashing_count, thrashing_delay) = if version >= 9 {
+        (read_u64(buf, 328), nanoseconds(336))
+    } else {
+        (None, None)
+    };
+
+    Some(Taskstats {
+        version,
+        cpu_count: read_u64(buf, 16)?,
+        cpu_delay: nanoseconds(24)?,
+        blkio_count: read_u64(buf, 32)?,
+        blkio_delay: nanoseconds(40)?,
+        swapin_count: read_u64(buf, 48)?,
+        swapin_delay: nanoseconds(56)?,
+        cpu_run_real: nanoseconds(64)?,
+        cpu_run_virtual: nanoseconds(72)?,
+        voluntary_ctx_switches: read_u64(buf, 272)?,
+        involuntary_ctx_switches: read_u64(buf, 280)?,
+        freepages_count,
+        freepages_delay,
+        thrashing_count,
+        thrashing_delay,
+    })
+}
+
+fn request(pid: Pid) -> io::Result<Option<Taskstats>> {
+    let mut socket = Socket::open()?;
+
+    // Family id is assigned dynamically by kernel
+    let attrs = socket.request(
+        libc::GENL_ID_CTRL as u16,
+        libc::CTRL_CMD_GETFAMILY as u8,
+        1,
+        libc::CTRL_ATTR_FAMILY_NAME as u16,
+        TASKSTATS_GENL_NAME,
+    )?;
+    let family = match find_attribute(attrs, libc::CTRL_ATTR_FAMILY_ID as u16)
+        .and_then(|payload| read_u16(payload, 0))
+    {
+        Some(family) => family,
+        None => return Ok(None),
+    };
+
+    let attrs = socket.request(
+        family,
+        TASKSTATS_CMD_GET,
+        TASKSTATS_GENL_VERSION,
+        TASKSTATS_CMD_ATTR_TGID,
+        &(pid as u32).to_ne_bytes(),
+    )?;
+
+    Ok(find_attribute(attrs, TASKSTATS_TYPE_AGGR_TGID)
+        .and_then(|aggr| find_attribute(aggr, TASKSTATS_TYPE_STATS))
+        .and_then(parse_stats))
+}
+
+fn load(pid: Pid) -> ProcessResult<Option<Taskstats>> {
+    match request(pid) {
+        Ok(stats) => Ok(stats),
+        Err(e) if e.raw_os_error() == Some(libc::ESRCH) => Err(ProcessError::NoSuchProcess(pid)),
+        // Taskstats interface is optional: kernel might be built without it,
+        // the current process might be not in the initial network namespace
+        // or restricted from opening the netlink sockets at all
+        Err(..) => Ok(None),
+    }
+}
+
+/// Fetches the statistics aggregated over all the threads of the `pid` process.
+///
+/// `Ok(None)` is returned if taskstats interface is not available.
+pub async fn taskstats(pid: Pid) -> ProcessResult<Option<Taskstats>> {
+    rt::spawn_blocking(move || load(pid)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::time;
+
+    use super::{find_attribute, parse_response, parse_stats};
+
+    fn attribute(kind: u16, payload: &[u8]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&(4 + payload.len() as u16).to_ne_bytes());
+        buf.extend_from_slice(&kind.to_ne_bytes());
+        buf.extend_from_slice(payload);
+        buf.resize((buf.len() + 3) & !3, 0);
+
+        buf
+    }
+
+    fn message(kind: u16, payload: &[u8]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&(16 + payload.len() as u32).to_ne_bytes());
+        buf.extend_from_slice(&kind.to_ne_bytes());
+        buf.extend_from_slice(&[0; 10]);
+        buf.extend_from_slice(payload);
+
+        buf
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let buf = message(libc::NLMSG_ERROR as u16, &(-libc::EPERM).to_ne_bytes());
+
+        let e = parse_response(&buf, 0x17).unwrap_err();
+        assert_eq!(e.raw_os_error(), Some(libc::EPERM));
+    }
+
+    #[test]
+    fn test_parse_stats() {
+        let mut stats = vec![0u8; 344];
+        stats[0..2].copy_from_slice(&8u16.to_ne_bytes());
+        stats[16..24].copy_from_slice(&3u64.to_ne_bytes());
+        stats[24..32].copy_from_slice(&1_500_000_000u64.to_ne_bytes());
+        stats[56..64].copy_from_slice(&250_000_000u64.to_ne_bytes());
+        stats[272..280].copy_from_slice(&42u64.to_ne_bytes());
+        stats[312..320].copy_from_slice(&5u64.to_ne_bytes());
+
+        let mut aggr = attribute(1, &1234u32.to_ne_bytes());
+        aggr.extend(attribute(3, &stats));
+        let mut payload = vec![1, 1, 0, 0];
+        // `NLA_F_NESTED` flag is set by newer kernels
+        payload.extend(attribute(5 | 0x8000, &aggr));
+        let buf = message(0x17, &payload);
+
+        let attrs = parse_response(&buf, 0x17).unwrap();
+        let stats = find_attribute(attrs, 5)
+            .and_then(|aggr| find_attribute(aggr, 3))
+            .and_then(parse_stats)
+            .unwrap();
+
+        assert_eq!(stats.version(), 8);
+        assert_eq!(stats.cpu_count(), 3);
+        assert_eq!(stats.cpu_delay().get::<time::second>(), 1.5);
+        assert_eq!(stats.swapin_delay().get::<time::millisecond>(), 250.0);
+        assert_eq!(stats.voluntary_ctx_switches(), 42);
+        assert_eq!(stats.freepages_count(), Some(5));
+        assert_eq!(stats.thrashing_count(), None);
+    }
+}
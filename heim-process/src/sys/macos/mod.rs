@@ -7,3 +7,4 @@ mod utils;
 
 pub use self::pids::*;
 pub use self::process::*;
+pub use crate::sys::unix::pid_wait4;
@@ -4,6 +4,7 @@ use std::ffi::CStr;
 use std::hash;
 use std::io;
 use std::path::PathBuf;
+use std::process::ExitStatus;
 
 use ::futures::future::BoxFuture;
 
@@ -47,6 +48,12 @@ impl Process {
         }
     }
 
+    pub async fn children_pids(&self) -> ProcessResult<Option<Vec<Pid>>> {
+        // There is no cheap way to fetch process children,
+        // so the generic processes scan is used instead
+        Ok(None)
+    }
+
     pub async fn name(&self) -> ProcessResult<String> {
         match bindings::process(self.pid) {
             Ok(kinfo_proc) => {
@@ -160,7 +167,7 @@ impl Process {
         self._signal(Signal::Kill).await
     }
 
-    pub async fn wait(&self) -> ProcessResult<()> {
+    pub async fn wait(&self) -> ProcessResult<Option<ExitStatus>> {
         pid_wait(self.pid).await
     }
 
@@ -1,5 +1,7 @@
 mod common;
 
+pub(crate) use self::common::MAX_NAME_LEN;
+
 #[cfg(unix)]
 mod unix;
 
@@ -1,6 +1,15 @@
 mod bindings;
 mod env;
 mod process;
+// Used by the Linux implementation only at the moment
+#[cfg(target_os = "linux")]
+mod users;
 
 pub use self::env::{Environment, EnvironmentIter, IntoEnvironmentIter};
-pub use self::process::{pid_exists, pid_kill, pid_priority, pid_setpriority, pid_wait};
+pub use self::process::{pid_exists, pid_kill, pid_priority, pid_setpriority, pid_wait, pid_wait4};
+#[cfg(target_os = "linux")]
+pub use self::process::{
+    pid_getaffinity, pid_ioprio_get, pid_ioprio_set, pid_prlimit, pid_setaffinity,
+};
+#[cfg(target_os = "linux")]
+pub use self::users::username;
@@ -1,13 +1,21 @@
 use std::io;
+use std::mem;
+use std::os::unix::process::ExitStatusExt;
+use std::process::ExitStatus;
 use std::ptr;
 use std::time::Duration;
 
 use heim_common::prelude::StreamExt as _;
+use heim_common::units::{information, time, Information, Time};
 use heim_common::Error;
 use heim_runtime as rt;
 
 use super::bindings::{errno, set_errno};
-use crate::os::unix::Signal;
+#[cfg(target_os = "linux")]
+use crate::os::linux::{CpuSet, IoClass, IoPriority};
+#[cfg(target_os = "linux")]
+use crate::os::unix::{Limit, Resource};
+use crate::os::unix::{ResourceUsage, Signal};
 use crate::{Pid, ProcessError, ProcessResult};
 
 #[cfg(all(target_os = "linux", not(target_env = "musl")))]
@@ -52,23 +60,26 @@ pub fn pid_kill(pid: Pid, signal: Signal) -> ProcessResult<()> {
 }
 
 /// Wait for the process termination.
-pub async fn pid_wait(pid: Pid) -> ProcessResult<()> {
+///
+/// Exit status is returned only if process is a child of the current one.
+pub async fn pid_wait(pid: Pid) -> ProcessResult<Option<ExitStatus>> {
     // `waitpid` might block indefinitely,
     // we need to handle that
     let waited = rt::spawn_blocking(move || {
-        let result = unsafe { libc::waitpid(pid, ptr::null_mut(), 0) };
+        let mut status = 0;
+        let result = unsafe { libc::waitpid(pid, &mut status, 0) };
         if result == -1 {
             // Do not care about the error type at this point
             Err(())
         } else {
-            Ok(())
+            Ok(ExitStatus::from_raw(status))
         }
     })
     .await;
 
     // Task finished correctly and `waitpid` succeeded too
-    if waited.is_ok() {
-        return Ok(());
+    if let Ok(status) = waited {
+        return Ok(Some(status));
     }
     // If either task failed or `waitpid` failed,
     // it could mean that either `pid` is not our child
@@ -80,7 +91,7 @@ pub async fn pid_wait(pid: Pid) -> ProcessResult<()> {
     futures::pin_mut!(interval);
     while let Some(..) = interval.next().await {
         if !pid_exists(pid) {
-            return Ok(());
+            return Ok(None);
         }
     }
 
@@ -92,6 +103,56 @@ pub async fn pid_wait(pid: Pid) -> ProcessResult<()> {
     Err(e.into())
 }
 
+/// Wait for the child process termination and collect its resource usage.
+///
+/// Unlike [pid_wait], the `pid` given must be a child of the current process.
+pub async fn pid_wait4(pid: Pid) -> ProcessResult<(ExitStatus, ResourceUsage)> {
+    rt::spawn_blocking(move || loop {
+        let mut status = 0;
+        let mut usage = mem::MaybeUninit::<libc::rusage>::uninit();
+        let result = unsafe { libc::wait4(pid, &mut status, 0, usage.as_mut_ptr()) };
+        if result != -1 {
+            let usage = unsafe { usage.assume_init() };
+            return Ok((ExitStatus::from_raw(status), usage.into()));
+        }
+
+        let e = io::Error::last_os_error();
+        match e.raw_os_error() {
+            Some(libc::EINTR) => continue,
+            // Child was already reaped by someone else
+            Some(libc::ECHILD) => return Err(ProcessError::NoSuchProcess(pid)),
+            _ => return Err(Error::from(e).with_ffi("wait4").into()),
+        }
+    })
+    .await
+}
+
+#[allow(trivial_numeric_casts)]
+impl From<libc::rusage> for ResourceUsage {
+    fn from(usage: libc::rusage) -> ResourceUsage {
+        let timeval = |value: libc::timeval| {
+            Time::new::<time::second>(value.tv_sec as f64)
+                + Time::new::<time::microsecond>(value.tv_usec as f64)
+        };
+
+        // `ru_maxrss` is measured in kibibytes on Linux, but in bytes on macOS
+        #[cfg(target_os = "macos")]
+        let max_rss = Information::new::<information::byte>(usage.ru_maxrss as u64);
+        #[cfg(not(target_os = "macos"))]
+        let max_rss = Information::new::<information::kibibyte>(usage.ru_maxrss as u64);
+
+        ResourceUsage {
+            max_rss,
+            user_time: timeval(usage.ru_utime),
+            system_time: timeval(usage.ru_stime),
+            minor_faults: usage.ru_minflt as u64,
+            major_faults: usage.ru_majflt as u64,
+            voluntary_ctx_switches: usage.ru_nvcsw as u64,
+            involuntary_ctx_switches: usage.ru_nivcsw as u64,
+        }
+    }
+}
+
 /// Safe wrapper for POSIX `getpriority`
 pub fn pid_priority(pid: Pid) -> ProcessResult<libc::c_int> {
     // Since `getpriority()` can legitimately return the value `-1`,
@@ -119,3 +180,152 @@ pub fn pid_setpriority(pid: Pid, value: i32) -> ProcessResult<()> {
         Err(Error::last_os_error().into())
     }
 }
+
+/// Safe wrapper for Linux-specific `prlimit`, which sets the new resource limit only.
+///
+/// Current limits are read from the `/proc/<pid>/limits` file instead.
+#[cfg(target_os = "linux")]
+#[allow(trivial_numeric_casts)]
+pub fn pid_prlimit(pid: Pid, resource: Resource, limit: Limit) -> ProcessResult<()> {
+    let resource = match resource {
+        Resource::Cpu => libc::RLIMIT_CPU,
+        Resource::FileSize => libc::RLIMIT_FSIZE,
+        Resource::Data => libc::RLIMIT_DATA,
+        Resource::Stack => libc::RLIMIT_STACK,
+        Resource::Core => libc::RLIMIT_CORE,
+        Resource::Rss => libc::RLIMIT_RSS,
+        Resource::NumProcesses => libc::RLIMIT_NPROC,
+        Resource::NumFiles => libc::RLIMIT_NOFILE,
+        Resource::MemoryLock => libc::RLIMIT_MEMLOCK,
+        Resource::AddressSpace => libc::RLIMIT_AS,
+        Resource::Locks => libc::RLIMIT_LOCKS,
+        Resource::SignalsPending => libc::RLIMIT_SIGPENDING,
+        Resource::MessageQueue => libc::RLIMIT_MSGQUEUE,
+        Resource::Nice => libc::RLIMIT_NICE,
+        Resource::RealtimePriority => libc::RLIMIT_RTPRIO,
+        Resource::RealtimeTimeout => libc::RLIMIT_RTTIME,
+    };
+    let value = |value: Option<u64>| match value {
+        Some(value) => value as libc::rlim_t,
+        None => libc::RLIM_INFINITY,
+    };
+    let new_limit = libc::rlimit {
+        rlim_cur: value(limit.soft()),
+        rlim_max: value(limit.hard()),
+    };
+
+    let result = unsafe { libc::prlimit(pid, resource, &new_limit, ptr::null_mut()) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(last_pid_error(pid, "prlimit"))
+    }
+}
+
+/// Safe wrapper for Linux-specific `sched_getaffinity`
+#[cfg(target_os = "linux")]
+pub fn pid_getaffinity(pid: Pid) -> ProcessResult<CpuSet> {
+    let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+    let result =
+        unsafe { libc::sched_getaffinity(pid, mem::size_of::<libc::cpu_set_t>(), &mut set) };
+    if result != 0 {
+        return Err(last_pid_error(pid, "sched_getaffinity"));
+    }
+
+    let cpus = (0..libc::CPU_SETSIZE as usize).filter(|cpu| unsafe { libc::CPU_ISSET(*cpu, &set) });
+
+    Ok(cpus.collect())
+}
+
+/// Safe wrapper for Linux-specific `sched_setaffinity`
+#[cfg(target_os = "linux")]
+pub fn pid_setaffinity(pid: Pid, cpus: &CpuSet) -> ProcessResult<()> {
+    let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+    for cpu in cpus.iter() {
+        if cpu >= libc::CPU_SETSIZE as usize {
+            let e = Error::from(io::Error::from(io::ErrorKind::InvalidInput))
+                .with_message(format!("CPU {} is out of the supported range", cpu));
+            return Err(e.into());
+        }
+        unsafe { libc::CPU_SET(cpu, &mut set) };
+    }
+
+    let result = unsafe { libc::sched_setaffinity(pid, mem::size_of::<libc::cpu_set_t>(), &set) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(last_pid_error(pid, "sched_setaffinity"))
+    }
+}
+
+/// Maps the last OS error of the syscall applied to the `pid`.
+#[cfg(target_os = "linux")]
+fn last_pid_error(pid: Pid, ffi: &'static str) -> ProcessError {
+    let e = io::Error::last_os_error();
+    match e.raw_os_error() {
+        Some(libc::ESRCH) => ProcessError::NoSuchProcess(pid),
+        Some(libc::EPERM) => ProcessError::AccessDenied(pid),
+        _ => Error::from(e).with_ffi(ffi).into(),
+    }
+}
+
+#[cfg(target_os = "linux")]
+const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+#[cfg(target_os = "linux")]
+const IOPRIO_CLASS_SHIFT: libc::c_int = 13;
+#[cfg(target_os = "linux")]
+const IOPRIO_PRIO_LEVEL: libc::c_int = 0x7;
+
+/// Safe wrapper for Linux-specific `ioprio_get`, which has no glibc wrapper
+#[cfg(target_os = "linux")]
+pub fn pid_ioprio_get(pid: Pid) -> ProcessResult<IoPriority> {
+    let result = unsafe { libc::syscall(libc::SYS_ioprio_get, IOPRIO_WHO_PROCESS, pid) };
+    if result == -1 {
+        return Err(last_pid_error(pid, "ioprio_get"));
+    }
+
+    let value = result as libc::c_int;
+    let class = match value >> IOPRIO_CLASS_SHIFT {
+        0 => IoClass::None,
+        1 => IoClass::RealTime,
+        2 => IoClass::BestEffort,
+        3 => IoClass::Idle,
+        other => {
+            let e = Error::from(io::Error::from(io::ErrorKind::InvalidData))
+                .with_message(format!("Unknown I/O scheduling class {}", other));
+            return Err(e.into());
+        }
+    };
+    // Priority hints, which are stored in the upper data bits by newer kernels, are ignored
+    let level = (value & IOPRIO_PRIO_LEVEL) as u8;
+
+    Ok(IoPriority { class, level })
+}
+
+/// Safe wrapper for Linux-specific `ioprio_set`, which has no glibc wrapper
+#[cfg(target_os = "linux")]
+pub fn pid_ioprio_set(pid: Pid, priority: IoPriority) -> ProcessResult<()> {
+    // Newer kernels are using the upper level bits for priority hints
+    // and are not rejecting the out of range levels
+    if libc::c_int::from(priority.level()) > IOPRIO_PRIO_LEVEL {
+        let e = Error::from(io::Error::from(io::ErrorKind::InvalidInput)).with_message(format!(
+            "I/O priority level {} is out of range",
+            priority.level()
+        ));
+        return Err(e.into());
+    }
+    let class = match priority.class() {
+        IoClass::None => 0,
+        IoClass::RealTime => 1,
+        IoClass::BestEffort => 2,
+        IoClass::Idle => 3,
+    };
+    let value: libc::c_int = (class << IOPRIO_CLASS_SHIFT) | libc::c_int::from(priority.level());
+
+    let result = unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, pid, value) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(last_pid_error(pid, "ioprio_set"))
+    }
+}
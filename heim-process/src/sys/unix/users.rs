@@ -0,0 +1,51 @@
+use std::ffi::CStr;
+use std::mem;
+use std::ptr;
+
+use heim_common::Error;
+
+use crate::ProcessResult;
+
+/// Resolves the user name for the `uid` with the passwd database.
+///
+/// Might block for a while if the passwd database is a remote one (NSS),
+/// so it is better to call it in a blocking task.
+pub fn username(uid: libc::uid_t) -> ProcessResult<Option<String>> {
+    let mut buffer_size = match unsafe { libc::sysconf(libc::_SC_GETPW_R_SIZE_MAX) } {
+        size if size > 0 => size as usize,
+        // Value is indeterminate, using the same default as glibc does
+        _ => 1024,
+    };
+
+    loop {
+        let mut passwd = mem::MaybeUninit::<libc::passwd>::uninit();
+        let mut buffer: Vec<libc::c_char> = Vec::with_capacity(buffer_size);
+        let mut result = ptr::null_mut();
+
+        let code = unsafe {
+            libc::getpwuid_r(
+                uid,
+                passwd.as_mut_ptr(),
+                buffer.as_mut_ptr(),
+                buffer_size,
+                &mut result,
+            )
+        };
+
+        match code {
+            0 if result.is_null() => return Ok(None),
+            0 => {
+                let passwd = unsafe { passwd.assume_init() };
+                let name = unsafe { CStr::from_ptr(passwd.pw_name) };
+
+                return Ok(Some(name.to_string_lossy().into_owned()));
+            }
+            libc::ERANGE => buffer_size *= 2,
+            other => {
+                return Err(Error::from_raw_os_error(other)
+                    .with_ffi("getpwuid_r")
+                    .into())
+            }
+        }
+    }
+}
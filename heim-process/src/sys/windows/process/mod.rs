@@ -3,6 +3,7 @@ use std::ffi::OsString;
 use std::hash;
 use std::os::windows::ffi::OsStringExt;
 use std::path::PathBuf;
+use std::process::ExitStatus;
 
 use heim_common::prelude::*;
 use heim_common::units::Time;
@@ -49,6 +50,19 @@ impl Process {
             .unwrap_or_else(|| Err(ProcessError::NoSuchProcess(self.pid)))
     }
 
+    pub async fn children_pids(&self) -> ProcessResult<Option<Vec<Pid>>> {
+        let snapshot = bindings::snapshot::Snapshot::new()?;
+
+        let children = snapshot
+            .flatten()
+            // System Idle Process is a parent of itself
+            .filter(|entry| entry.th32ParentProcessID == self.pid && entry.th32ProcessID != self.pid)
+            .map(|entry| entry.th32ProcessID)
+            .collect();
+
+        Ok(Some(children))
+    }
+
     pub async fn name(&self) -> ProcessResult<String> {
         let res = match self.pid {
             0 => Ok("System Idle Process".to_string()),
@@ -187,7 +201,7 @@ impl Process {
         handle.terminate().map_err(Into::into)
     }
 
-    pub async fn wait(&self) -> ProcessResult<()> {
+    pub async fn wait(&self) -> ProcessResult<Option<ExitStatus>> {
         unimplemented!()
     }
 
@@ -1,5 +1,6 @@
 #![cfg(unix)]
 
+use std::os::unix::process::ExitStatusExt;
 use std::process::{Command, Stdio};
 
 use heim_process as process;
@@ -37,3 +38,31 @@ async fn test_kill() {
         Err(e) => panic!("Process::signal failed to kill the test process: {:#?}", e),
     }
 }
+
+#[heim_derive::test]
+async fn test_wait_exit_status() {
+    // Child is reaped by the `Process::wait` itself
+    let pid = Command::new("sh")
+        .arg("-c")
+        .arg("exit 3")
+        .spawn()
+        .unwrap()
+        .id();
+
+    let process = process::get(pid as process::Pid).await.unwrap();
+    let status = process.wait().await.unwrap();
+    assert_eq!(status.and_then(|status| status.code()), Some(3));
+}
+
+#[heim_derive::test]
+async fn test_wait_killed() {
+    let pid = Command::new("sleep").arg("10").spawn().unwrap().id();
+
+    let process = process::get(pid as process::Pid).await.unwrap();
+    process.kill().await.unwrap();
+    let status = process.wait().await.unwrap();
+    assert_eq!(
+        status.and_then(|status| status.signal()),
+        Some(libc::SIGKILL)
+    );
+}
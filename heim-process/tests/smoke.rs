@@ -73,13 +73,52 @@ async fn smoke_processes() -> Result<()> {
             use heim_process::os::unix::ProcessExt;
 
             try_method!(process.niceness());
+            #[cfg(target_os = "linux")]
+            try_method!(process.rlimits());
         }
 
         #[cfg(target_os = "linux")]
         {
-            use heim_process::os::linux::ProcessExt;
+            use heim_process::os::linux::{ConnectionKind, ProcessExt};
 
             try_method!(process.net_io_counters());
+            try_method!(process.threads());
+            try_method!(process.open_files());
+            try_method!(process.num_fds());
+            try_method!(process.memory_full_info());
+            try_method!(process.memory_maps());
+            try_method!(process.uids());
+            try_method!(process.gids());
+            try_method!(process.groups());
+            try_method!(process.username());
+            try_method!(process.capabilities());
+            try_method!(process.seccomp());
+            try_method!(process.no_new_privs());
+            try_method!(process.speculation_store_bypass());
+            try_method!(process.namespaces());
+            try_method!(process.cgroups());
+            try_method!(process.cgroup_stats());
+            try_method!(process.cpu_affinity());
+            try_method!(process.cpus_allowed());
+            try_method!(process.mems_allowed());
+            try_method!(process.ionice());
+            try_method!(process.page_faults());
+            try_method!(process.num_ctx_switches());
+            try_method!(process.num_threads());
+            try_method!(process.process_group());
+            try_method!(process.session_id());
+            try_method!(process.terminal());
+            try_method!(process.terminal_process_group());
+            try_method!(process.scheduler());
+            try_method!(process.wchan());
+            try_method!(process.kernel_stack());
+            try_method!(process.syscall());
+            try_method!(process.oom_score());
+            try_method!(process.oom_score_adj());
+            try_method!(process.connections(ConnectionKind::All));
+            try_method!(process.cpu_usage_with_children());
+            try_method!(process.ns_pids());
+            try_method!(process.delay_accounting());
         }
 
         #[cfg(target_os = "windows")]
@@ -92,3 +131,134 @@ async fn smoke_processes() -> Result<()> {
 
     Ok(())
 }
+
+#[heim_derive::test]
+async fn smoke_process_tree() -> process::ProcessResult<()> {
+    let current = process::current().await?;
+    let tree = process::process_tree().await?;
+
+    assert!(!tree.is_empty());
+    assert!(tree.roots().next().is_some());
+    assert_eq!(tree.get(current.pid()), Some(&current));
+    for child in tree.children(current.pid()) {
+        assert_eq!(child.parent_pid().await?, current.pid());
+    }
+
+    let _ = current.children().await?;
+    let _ = current.descendants().await?;
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_events() -> process::ProcessResult<()> {
+    let events = process::events().await?;
+    ::futures::pin_mut!(events);
+
+    // Child should outlive the polling interval in case if there is no events connector available
+    let child = std::process::Command::new("sleep").arg("2").spawn()?;
+    let pid = child.id() as process::Pid;
+    let waiter = std::thread::spawn(move || child.wait_with_output());
+
+    while let Some(event) = events.next().await {
+        if let process::Event::Exit { pid: exited, .. } = event? {
+            if exited == pid {
+                break;
+            }
+        }
+    }
+    let _ = waiter.join();
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_snapshot() -> process::ProcessResult<()> {
+    let current = process::current().await?;
+    let info = current.snapshot(process::Attributes::all()).await;
+
+    assert_eq!(info.pid(), current.pid());
+    assert_eq!(
+        info.name().and_then(|name| name.as_ref().ok()),
+        Some(&current.name().await?)
+    );
+    assert_eq!(
+        info.create_time().and_then(|time| time.as_ref().ok()),
+        Some(&current.create_time().await?)
+    );
+    assert!(info.parent_pid().is_some());
+    assert!(info.exe().is_some());
+    assert!(info.command().is_some());
+    assert!(info.cwd().is_some());
+    assert!(info.status().is_some());
+    assert!(info.cpu_time().is_some());
+    assert!(info.memory().is_some());
+    #[cfg(target_os = "linux")]
+    assert!(info.username().is_some());
+
+    let info = current.snapshot(process::Attributes::STATUS).await;
+    assert!(info.name().is_none());
+    assert!(info.status().is_some());
+
+    let processes = process::processes_with(process::Attributes::NAME).await?;
+    ::futures::pin_mut!(processes);
+    while let Some(info) = processes.next().await {
+        match info {
+            Ok(info) => assert!(info.name().is_some()),
+            Err(ProcessError::NoSuchProcess(..)) | Err(ProcessError::AccessDenied(..)) => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_find() -> process::ProcessResult<()> {
+    let current = process::current().await?;
+    let processes = process::find()
+        .name(current.name().await?)
+        .parent_pid(current.parent_pid().await?)
+        .command_glob("*")
+        .processes()
+        .await?;
+    ::futures::pin_mut!(processes);
+
+    let mut found = false;
+    while let Some(process) = processes.next().await {
+        found |= process? == current;
+    }
+    assert!(found);
+
+    assert!(process::find()
+        .command_regex("(")
+        .processes()
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_spawn() -> process::ProcessResult<()> {
+    let mut command = std::process::Command::new("sleep");
+    command.arg("0");
+    let mut child = process::spawn(command).await?;
+    assert_eq!(child.pid(), child.child().id() as process::Pid);
+    try_method!(child.memory());
+
+    let status = child.wait().await?;
+    assert!(status.success());
+    assert_eq!(child.wait().await?, status);
+
+    #[cfg(unix)]
+    {
+        use heim_common::units::information;
+        use process::os::unix::ChildExt;
+
+        let usage = child.resource_usage().expect("Child was reaped");
+        assert!(usage.max_rss().get::<information::byte>() > 0);
+    }
+
+    Ok(())
+}
@@ -6,7 +6,7 @@ use futures::{io::BufReader, AsyncBufReadExt, Stream, StreamExt, TryStreamExt};
 use smol::unblock;
 
 // Public re-exports
-pub use smol::fs::{read, read_dir, read_link, read_to_string, File};
+pub use smol::fs::{read, read_dir, read_link, read_to_string, write, File};
 
 pub async fn path_exists<T>(path: T) -> bool
 where
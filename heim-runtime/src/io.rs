@@ -0,0 +1,33 @@
+//! Asynchronous I/O readiness for the raw file descriptors.
+
+use std::io;
+use std::os::unix::io::AsRawFd;
+
+/// File descriptor registered in the async reactor.
+///
+/// File descriptor is switched into the non-blocking mode.
+#[derive(Debug)]
+pub struct Async<T>(smol::Async<T>);
+
+impl<T: AsRawFd> Async<T> {
+    /// Registers `io` in the async reactor.
+    pub fn new(io: T) -> io::Result<Async<T>> {
+        smol::Async::new(io).map(Async)
+    }
+
+    /// Returns a reference to the inner I/O handle.
+    pub fn get_ref(&self) -> &T {
+        self.0.get_ref()
+    }
+
+    /// Waits until the file descriptor becomes readable.
+    pub async fn readable(&self) -> io::Result<()> {
+        self.0.readable().await
+    }
+
+    /// Performs the read operation, waiting for the readiness
+    /// while it fails with the `WouldBlock` error.
+    pub async fn read_with<R>(&self, op: impl FnMut(&T) -> io::Result<R>) -> io::Result<R> {
+        self.0.read_with(op).await
+    }
+}
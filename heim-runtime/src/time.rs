@@ -2,7 +2,8 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 use std::time::Duration;
 
-use futures::{future::Future, stream::Stream};
+use futures::future::{self, Either, Future};
+use futures::stream::Stream;
 use futures_timer::Delay;
 
 /// Naive interval stream implementation.
@@ -33,3 +34,14 @@ pub fn interval(duration: Duration) -> Interval {
         delay: Delay::new(duration),
     }
 }
+
+/// Awaits for the `future` completion for the `duration` at most.
+///
+/// `None` is returned if `future` was not completed in time.
+pub async fn timeout<F: Future>(duration: Duration, future: F) -> Option<F::Output> {
+    futures::pin_mut!(future);
+    match future::select(future, Delay::new(duration)).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(..) => None,
+    }
+}
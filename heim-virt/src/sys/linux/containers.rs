@@ -2,6 +2,7 @@ use std::marker::Unpin;
 use std::path::Path;
 
 use heim_common::prelude::{future, StreamExt, TryFutureExt};
+use heim_common::sys::linux::parse_cgroup_line;
 use heim_runtime as rt;
 
 use crate::Virtualization;
@@ -55,12 +56,19 @@ where
     futures::pin_mut!(lines);
 
     while let Some(line) = lines.next().await {
-        match line {
-            // TODO: Is it `lxc` or `lxc-libvirt` here?
-            Ok(l) if l.contains("lxc") => return Ok(Virtualization::Lxc),
-            Ok(l) if l.contains("docker") => return Ok(Virtualization::Docker),
-            Ok(l) if l.contains("rkt") => return Ok(Virtualization::Rkt),
+        let path = match line.map(|l| parse_cgroup_line(&l)) {
+            Ok(Ok((_, _, path))) => path,
             _ => continue,
+        };
+        let path = path.to_string_lossy();
+
+        // TODO: Is it `lxc` or `lxc-libvirt` here?
+        if path.contains("lxc") {
+            return Ok(Virtualization::Lxc);
+        } else if path.contains("docker") {
+            return Ok(Virtualization::Docker);
+        } else if path.contains("rkt") {
+            return Ok(Virtualization::Rkt);
         }
     }
 